@@ -0,0 +1,122 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use llmur::{
+	azure::v2024_02_01::chat_completion::transformer::from_openai_v1::request::TransformationContext,
+	openai::v1::chat_completion::{
+		request::ChatCompletionRequest, response::ChatCompletionChunkResponse,
+	},
+};
+use serde_json::json;
+
+fn fx_chat_completion_request() -> String {
+	json!({
+	  "model": "gpt-4o",
+	  "messages": [
+		{
+		  "role": "system",
+		  "content": "You are a helpful assistant."
+		},
+		{
+		  "role": "user",
+		  "content": [
+			{
+			  "type": "text",
+			  "text": "What's in this image?"
+			},
+			{
+			  "type": "image_url",
+			  "image_url": {
+				"url": "https://example.com/image.jpg"
+			  }
+			}
+		  ]
+		}
+	  ],
+	  "tools": [
+		{
+		  "type": "function",
+		  "function": {
+			"name": "get_current_weather",
+			"description": "Get the current weather in a given location",
+			"parameters": {
+			  "type": "object",
+			  "properties": {
+				"location": { "type": "string" }
+			  },
+			  "required": ["location"]
+			}
+		  }
+		}
+	  ],
+	  "tool_choice": "auto",
+	  "max_tokens": 300,
+	  "stream": true
+	})
+	.to_string()
+}
+
+fn fx_chat_completion_chunk() -> String {
+	json!({
+	  "id": "chatcmpl-123",
+	  "object": "chat.completion.chunk",
+	  "created": 1694268190,
+	  "model": "gpt-4o",
+	  "system_fingerprint": "fp_44709d6fcb",
+	  "choices": [
+		{
+		  "index": 0,
+		  "delta": { "content": "Hello" },
+		  "logprobs": null,
+		  "finish_reason": null
+		}
+	  ]
+	})
+	.to_string()
+}
+
+fn bench_chat_completion_request(c: &mut Criterion) {
+	let fx_request = fx_chat_completion_request();
+	let request: ChatCompletionRequest = serde_json::from_str(&fx_request).unwrap();
+
+	let mut group = c.benchmark_group("chat_completion_request");
+	group.throughput(Throughput::Bytes(fx_request.len() as u64));
+	group.bench_function("decode_openai_v1", |b| {
+		b.iter(|| serde_json::from_str::<ChatCompletionRequest>(black_box(&fx_request)).unwrap())
+	});
+	group.bench_function("openai_v1_to_azure_v2024_02_01", |b| {
+		b.iter(|| {
+			black_box(&request).to_azure_v2024_02_01(TransformationContext { data_sources: None })
+		})
+	});
+	group.bench_function("decode_transform_encode_azure_v2024_02_01", |b| {
+		b.iter(|| {
+			let request: ChatCompletionRequest =
+				serde_json::from_str(black_box(&fx_request)).unwrap();
+			let transformation =
+				request.to_azure_v2024_02_01(TransformationContext { data_sources: None });
+			serde_json::to_string(&transformation.request).unwrap()
+		})
+	});
+	group.finish();
+}
+
+fn bench_chat_completion_stream(c: &mut Criterion) {
+	// Simulates a streamed completion, decoding every chunk as it would arrive.
+	let fx_chunk = fx_chat_completion_chunk();
+	let fx_chunks = vec![fx_chunk; 256];
+
+	let mut group = c.benchmark_group("chat_completion_stream");
+	group.throughput(Throughput::Elements(fx_chunks.len() as u64));
+	group.bench_function("decode_openai_v1_chunks", |b| {
+		b.iter(|| {
+			for chunk in black_box(&fx_chunks) {
+				black_box(serde_json::from_str::<ChatCompletionChunkResponse>(chunk).unwrap());
+			}
+		})
+	});
+	group.finish();
+}
+
+criterion_group!(benches, bench_chat_completion_request, bench_chat_completion_stream);
+criterion_main!(benches);
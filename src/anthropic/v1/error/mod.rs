@@ -0,0 +1,3 @@
+pub mod response;
+
+#[cfg(feature = "transformer")] pub mod transformer;
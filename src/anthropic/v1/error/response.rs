@@ -0,0 +1,52 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponse {
+	/// Object type. For errors, this is always "error".
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: String,
+	pub error: ErrorResponseError,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponseError {
+	/// The error type, such as `invalid_request_error`, `rate_limit_error` or
+	/// `overloaded_error`.
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: String,
+
+	/// A human-readable error message.
+	pub message: String,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_error_response_anthropic_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "type": "error",
+		  "error": {
+			"type": "not_found_error",
+			"message": "The requested resource could not be found."
+		  }
+		})
+		.to_string();
+
+		let data: ErrorResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.error.r#type, "not_found_error");
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
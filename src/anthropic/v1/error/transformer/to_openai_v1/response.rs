@@ -0,0 +1,89 @@
+use serde_json::json;
+
+use crate::openai::v1::error::response::{
+	ErrorResponse as OpenAIErrorResponse, ErrorResponseError as OpenAIErrorResponseError,
+};
+
+use crate::anthropic::v1::error::response::{
+	ErrorResponse as AnthropicErrorResponse, ErrorResponseError as AnthropicErrorResponseError,
+};
+
+impl AnthropicErrorResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		self.error.to_openai_v1()
+	}
+}
+
+impl AnthropicErrorResponseError {
+	/// Stream `error` events carry the inner error object only, so it can be transformed on its
+	/// own too.
+	pub fn to_openai_v1(&self) -> Transformation {
+		let (r#type, code) = match self.r#type.as_str() {
+			"authentication_error" => ("authentication_error", Some("invalid_api_key")),
+			"permission_error" => ("permission_error", None),
+			"not_found_error" => ("invalid_request_error", Some("not_found")),
+			"request_too_large" => ("invalid_request_error", Some("request_too_large")),
+			"rate_limit_error" => ("rate_limit_error", Some("rate_limit_exceeded")),
+			"api_error" | "overloaded_error" => ("server_error", None),
+			_ => ("invalid_request_error", None),
+		};
+
+		Transformation {
+			response: OpenAIErrorResponse {
+				error: OpenAIErrorResponseError {
+					message: self.message.clone(),
+					r#type: r#type.to_string(),
+					param: None,
+					code: code.map(|code| code.to_string()),
+					vendor: Some(json!({
+						"anthropic": {
+							"type": self.r#type,
+							"message": self.message,
+						}
+					})),
+				},
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct Transformation {
+	pub response: OpenAIErrorResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_overloaded_error_transform_ok() -> Result<()> {
+		let fx_response = AnthropicErrorResponse {
+			r#type: "error".to_string(),
+			error: AnthropicErrorResponseError {
+				r#type: "overloaded_error".to_string(),
+				message: "Overloaded".to_string(),
+			},
+		};
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.error.message, "Overloaded");
+		assert_eq!(data.response.error.r#type, "server_error");
+
+		// Check if the original error was attached under the vendor field.
+		let vendor = data.response.error.vendor.unwrap();
+		assert_eq!(vendor["anthropic"]["type"], json!("overloaded_error"));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,353 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesRequest {
+	/// The model that will complete your prompt. See [models](https://docs.anthropic.com/en/docs/models-overview) for additional details and options.
+	pub model: String,
+
+	/// Input messages. Models are trained to operate on alternating `user` and `assistant`
+	/// conversational turns. Consecutive turns with the same role are combined into a single turn.
+	pub messages: Vec<Message>,
+
+	/// The maximum number of tokens to generate before stopping. Different models have different
+	/// maximum values for this parameter.
+	pub max_tokens: u64,
+
+	/// System prompt. A system prompt is a way of providing context and instructions to Claude,
+	/// such as specifying a particular goal or role.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system: Option<MessagesSystem>,
+
+	/// An object describing metadata about the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub metadata: Option<MessagesMetadata>,
+
+	/// Custom text sequences that will cause the model to stop generating.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop_sequences: Option<Vec<String>>,
+
+	/// Whether to incrementally stream the response using server-sent events.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// Amount of randomness injected into the response. Defaults to 1.0. Ranges from 0.0 to 1.0.
+	/// Use temperature closer to 0.0 for analytical / multiple choice, and closer to 1.0 for
+	/// creative and generative tasks.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// Use nucleus sampling. You should either alter temperature or top_p, but not both.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// Only sample from the top K options for each subsequent token. Used to remove "long tail"
+	/// low probability responses.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// Definitions of tools that the model may use.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<Tool>>,
+
+	/// How the model should use the provided tools. The model can use a specific tool, any
+	/// available tool, decide by itself, or not use tools at all.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ToolChoice>,
+
+	/// Configuration for enabling Claude's extended thinking. When enabled, responses include
+	/// `thinking` content blocks showing Claude's thinking process before the final answer.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub thinking: Option<Thinking>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum Thinking {
+	#[cfg_attr(feature = "serde", serde(rename = "enabled", alias = "enabled"))]
+	Enabled {
+		/// Determines how many tokens Claude can use for its internal reasoning process. Must be
+		/// at least 1024 and less than `max_tokens`.
+		budget_tokens: u64,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "disabled", alias = "disabled"))]
+	Disabled,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum MessagesSystem {
+	TextSystem(String),
+	BlocksSystem(Vec<TextBlock>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum TextBlock {
+	#[cfg_attr(feature = "serde", serde(rename = "text", alias = "text"))]
+	Text { text: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesMetadata {
+	/// An external identifier for the user who is associated with the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub user_id: Option<String>,
+}
+
+// region:    --- Message
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Message {
+	pub role: MessageRole,
+	pub content: MessageContent,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageRole {
+	#[cfg_attr(feature = "serde", serde(rename = "user", alias = "user"))]
+	User,
+	#[cfg_attr(feature = "serde", serde(rename = "assistant", alias = "assistant"))]
+	Assistant,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum MessageContent {
+	TextContent(String),
+	BlocksContent(Vec<MessageContentBlock>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum MessageContentBlock {
+	#[cfg_attr(feature = "serde", serde(rename = "text", alias = "text"))]
+	TextBlock { text: String },
+	#[cfg_attr(feature = "serde", serde(rename = "image", alias = "image"))]
+	ImageBlock { source: ImageSource },
+	#[cfg_attr(feature = "serde", serde(rename = "tool_use", alias = "tool_use"))]
+	ToolUseBlock { id: String, name: String, input: serde_json::Value },
+	#[cfg_attr(feature = "serde", serde(rename = "tool_result", alias = "tool_result"))]
+	ToolResultBlock {
+		tool_use_id: String,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		content: Option<ToolResultContent>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		is_error: Option<bool>,
+	},
+	/// A thinking block from a previous response, which has to be sent back unmodified before
+	/// the tool use blocks of the last assistant turn when extended thinking is enabled.
+	#[cfg_attr(feature = "serde", serde(rename = "thinking", alias = "thinking"))]
+	ThinkingBlock { thinking: String, signature: String },
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "redacted_thinking", alias = "redacted_thinking")
+	)]
+	RedactedThinkingBlock { data: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ImageSource {
+	#[cfg_attr(feature = "serde", serde(rename = "base64", alias = "base64"))]
+	Base64Source { media_type: String, data: String },
+	#[cfg_attr(feature = "serde", serde(rename = "url", alias = "url"))]
+	UrlSource { url: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum ToolResultContent {
+	TextContent(String),
+	BlocksContent(Vec<TextBlock>),
+}
+// endregion: --- Message
+
+// region:    --- Tools
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tool {
+	/// Name of the tool. This is how the tool will be called by the model and in tool_use blocks.
+	pub name: String,
+	/// Description of what this tool does.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub description: Option<String>,
+	/// [JSON schema](https://json-schema.org/) for this tool's input.
+	pub input_schema: serde_json::Value,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ToolChoice {
+	#[cfg_attr(feature = "serde", serde(rename = "auto", alias = "auto"))]
+	AutoChoice {
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		disable_parallel_tool_use: Option<bool>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "any", alias = "any"))]
+	AnyChoice {
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		disable_parallel_tool_use: Option<bool>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "tool", alias = "tool"))]
+	NamedChoice {
+		name: String,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		disable_parallel_tool_use: Option<bool>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "none", alias = "none"))]
+	NoneChoice,
+}
+// endregion: --- Tools
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_messages_anthropic_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "max_tokens": 2048,
+		  "system": "You are a helpful assistant.",
+		  "thinking": { "type": "enabled", "budget_tokens": 1024 },
+		  "messages": [
+			{"role": "user", "content": "Hello, world"}
+		  ]
+		})
+		.to_string();
+
+		let data: MessagesRequest = serde_json::from_str(&fx_request).unwrap();
+
+		assert_eq!(
+			data.system,
+			Some(MessagesSystem::TextSystem("You are a helpful assistant.".to_string()))
+		);
+		assert_eq!(data.messages[0].role, MessageRole::User);
+		assert_eq!(data.thinking, Some(Thinking::Enabled { budget_tokens: 1024 }));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_messages_anthropic_tools_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "max_tokens": 1024,
+		  "tools": [
+			{
+			  "name": "get_weather",
+			  "description": "Get the current weather in a given location",
+			  "input_schema": {
+				"type": "object",
+				"properties": {
+				  "location": { "type": "string" }
+				},
+				"required": ["location"]
+			  }
+			}
+		  ],
+		  "tool_choice": { "type": "tool", "name": "get_weather" },
+		  "messages": [
+			{"role": "user", "content": "What's the weather like in San Francisco?"},
+			{
+			  "role": "assistant",
+			  "content": [
+				{"type": "thinking", "thinking": "The user wants the weather.", "signature": "sig"},
+				{"type": "text", "text": "Let me check."},
+				{"type": "tool_use", "id": "toolu_01A09q90qw90lq917835lq9", "name": "get_weather", "input": {"location": "San Francisco, CA"}}
+			  ]
+			},
+			{
+			  "role": "user",
+			  "content": [
+				{"type": "tool_result", "tool_use_id": "toolu_01A09q90qw90lq917835lq9", "content": "15 degrees"}
+			  ]
+			}
+		  ]
+		})
+		.to_string();
+
+		let data: MessagesRequest = serde_json::from_str(&fx_request).unwrap();
+
+		assert_eq!(
+			data.tool_choice,
+			Some(ToolChoice::NamedChoice {
+				name: "get_weather".to_string(),
+				disable_parallel_tool_use: None
+			})
+		);
+		assert_eq!(
+			data.messages[1].content,
+			MessageContent::BlocksContent(vec![
+				MessageContentBlock::ThinkingBlock {
+					thinking: "The user wants the weather.".to_string(),
+					signature: "sig".to_string()
+				},
+				MessageContentBlock::TextBlock { text: "Let me check.".to_string() },
+				MessageContentBlock::ToolUseBlock {
+					id: "toolu_01A09q90qw90lq917835lq9".to_string(),
+					name: "get_weather".to_string(),
+					input: json!({ "location": "San Francisco, CA" })
+				},
+			])
+		);
+		assert_eq!(
+			data.messages[2].content,
+			MessageContent::BlocksContent(vec![MessageContentBlock::ToolResultBlock {
+				tool_use_id: "toolu_01A09q90qw90lq917835lq9".to_string(),
+				content: Some(ToolResultContent::TextContent("15 degrees".to_string())),
+				is_error: None,
+			}])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_messages_serializing_01_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_object = MessagesRequest {
+			model: "claude-3-5-haiku-20241022".to_string(),
+			messages: vec![Message {
+				role: MessageRole::User,
+				content: MessageContent::TextContent("Hello!".to_string()),
+			}],
+			max_tokens: 256,
+			system: None,
+			metadata: None,
+			stop_sequences: None,
+			stream: None,
+			temperature: None,
+			top_p: None,
+			top_k: None,
+			tools: None,
+			tool_choice: Some(ToolChoice::NoneChoice),
+			thinking: None,
+		};
+
+		let data = serde_json::to_value(fx_object)?;
+
+		assert_eq!(
+			data,
+			json!({
+			  "model": "claude-3-5-haiku-20241022",
+			  "messages": [{"role": "user", "content": "Hello!"}],
+			  "max_tokens": 256,
+			  "tool_choice": {"type": "none"}
+			})
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
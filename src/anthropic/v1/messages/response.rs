@@ -0,0 +1,214 @@
+use crate::anthropic::v1::error::response::ErrorResponseError;
+
+// region:    --- Message Response
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesResponse {
+	/// Unique object identifier.
+	pub id: String,
+
+	/// Object type. For Messages, this is always "message".
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: String,
+
+	/// Conversational role of the generated message. This will always be "assistant".
+	pub role: String,
+
+	/// Content generated by the model. This is an array of content blocks, each of which has a
+	/// type that determines its shape.
+	pub content: Vec<ResponseContentBlock>,
+
+	/// The model that handled the request.
+	pub model: String,
+
+	/// The reason that we stopped. This may be one of `end_turn`, `max_tokens`,
+	/// `stop_sequence`, `tool_use`, `pause_turn` or `refusal`. In the `message_start` event of a
+	/// stream this is null.
+	pub stop_reason: Option<String>,
+
+	/// Which custom stop sequence was generated, if any.
+	pub stop_sequence: Option<String>,
+
+	/// Billing and rate-limit usage.
+	pub usage: MessagesResponseUsage,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ResponseContentBlock {
+	#[cfg_attr(feature = "serde", serde(rename = "text", alias = "text"))]
+	TextBlock { text: String },
+	#[cfg_attr(feature = "serde", serde(rename = "tool_use", alias = "tool_use"))]
+	ToolUseBlock { id: String, name: String, input: serde_json::Value },
+	#[cfg_attr(feature = "serde", serde(rename = "thinking", alias = "thinking"))]
+	ThinkingBlock { thinking: String, signature: String },
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "redacted_thinking", alias = "redacted_thinking")
+	)]
+	RedactedThinkingBlock { data: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesResponseUsage {
+	/// The number of input tokens which were used.
+	pub input_tokens: u64,
+	/// The number of output tokens which were used.
+	pub output_tokens: u64,
+	/// The number of input tokens used to create the cache entry.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cache_creation_input_tokens: Option<u64>,
+	/// The number of input tokens read from the cache.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cache_read_input_tokens: Option<u64>,
+}
+// endregion: --- Message Response
+
+// region:    --- Stream Events
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum MessagesStreamEvent {
+	#[cfg_attr(feature = "serde", serde(rename = "message_start", alias = "message_start"))]
+	MessageStart { message: MessagesResponse },
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "content_block_start", alias = "content_block_start")
+	)]
+	ContentBlockStart { index: u64, content_block: ResponseContentBlock },
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "content_block_delta", alias = "content_block_delta")
+	)]
+	ContentBlockDelta { index: u64, delta: ContentBlockDelta },
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "content_block_stop", alias = "content_block_stop")
+	)]
+	ContentBlockStop { index: u64 },
+	#[cfg_attr(feature = "serde", serde(rename = "message_delta", alias = "message_delta"))]
+	MessageDelta { delta: MessageDelta, usage: MessageDeltaUsage },
+	#[cfg_attr(feature = "serde", serde(rename = "message_stop", alias = "message_stop"))]
+	MessageStop,
+	#[cfg_attr(feature = "serde", serde(rename = "ping", alias = "ping"))]
+	Ping,
+	#[cfg_attr(feature = "serde", serde(rename = "error", alias = "error"))]
+	Error { error: ErrorResponseError },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ContentBlockDelta {
+	#[cfg_attr(feature = "serde", serde(rename = "text_delta", alias = "text_delta"))]
+	TextDelta { text: String },
+	#[cfg_attr(feature = "serde", serde(rename = "input_json_delta", alias = "input_json_delta"))]
+	InputJsonDelta { partial_json: String },
+	#[cfg_attr(feature = "serde", serde(rename = "thinking_delta", alias = "thinking_delta"))]
+	ThinkingDelta { thinking: String },
+	#[cfg_attr(feature = "serde", serde(rename = "signature_delta", alias = "signature_delta"))]
+	SignatureDelta { signature: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageDelta {
+	pub stop_reason: Option<String>,
+	pub stop_sequence: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageDeltaUsage {
+	/// The cumulative number of output tokens which were used.
+	pub output_tokens: u64,
+}
+// endregion: --- Stream Events
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_messages_response_anthropic_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "msg_013Zva2CMHLNnXjNJJKqJ2EF",
+		  "type": "message",
+		  "role": "assistant",
+		  "content": [
+			{ "type": "text", "text": "I'll check the weather." },
+			{
+			  "type": "tool_use",
+			  "id": "toolu_01A09q90qw90lq917835lq9",
+			  "name": "get_weather",
+			  "input": { "location": "San Francisco, CA" }
+			}
+		  ],
+		  "model": "claude-3-5-sonnet-20241022",
+		  "stop_reason": "tool_use",
+		  "stop_sequence": null,
+		  "usage": {
+			"input_tokens": 2095,
+			"output_tokens": 503
+		  }
+		})
+		.to_string();
+
+		let data: MessagesResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.stop_reason, Some("tool_use".to_string()));
+		assert_eq!(
+			data.content[1],
+			ResponseContentBlock::ToolUseBlock {
+				id: "toolu_01A09q90qw90lq917835lq9".to_string(),
+				name: "get_weather".to_string(),
+				input: json!({ "location": "San Francisco, CA" }),
+			}
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_messages_stream_events_anthropic_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_events = vec![
+			json!({"type": "message_start", "message": {"id": "msg_1nZdL29xx5MUA1yADyHTEsnR8uuvGzszyY", "type": "message", "role": "assistant", "content": [], "model": "claude-3-5-sonnet-20241022", "stop_reason": null, "stop_sequence": null, "usage": {"input_tokens": 25, "output_tokens": 1}}}),
+			json!({"type": "content_block_start", "index": 0, "content_block": {"type": "text", "text": ""}}),
+			json!({"type": "ping"}),
+			json!({"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta", "text": "Hello"}}),
+			json!({"type": "content_block_stop", "index": 0}),
+			json!({"type": "content_block_start", "index": 1, "content_block": {"type": "tool_use", "id": "toolu_01T1x1fJ34qAmk2tNTrN7Up6", "name": "get_weather", "input": {}}}),
+			json!({"type": "content_block_delta", "index": 1, "delta": {"type": "input_json_delta", "partial_json": "{\"location\": \"San Fra"}}),
+			json!({"type": "content_block_stop", "index": 1}),
+			json!({"type": "message_delta", "delta": {"stop_reason": "tool_use", "stop_sequence": null}, "usage": {"output_tokens": 15}}),
+			json!({"type": "message_stop"}),
+			json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}),
+		];
+
+		let data: Vec<MessagesStreamEvent> = fx_events
+			.into_iter()
+			.map(serde_json::from_value)
+			.collect::<core::result::Result<_, _>>()?;
+
+		assert_eq!(data.len(), 11);
+		assert_eq!(
+			data[3],
+			MessagesStreamEvent::ContentBlockDelta {
+				index: 0,
+				delta: ContentBlockDelta::TextDelta { text: "Hello".to_string() }
+			}
+		);
+		assert_eq!(data[9], MessagesStreamEvent::MessageStop);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
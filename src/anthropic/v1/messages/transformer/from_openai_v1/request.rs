@@ -0,0 +1,755 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage as OpenAIChatCompletionMessage,
+	ChatCompletionReasoningEffort as OpenAIChatCompletionReasoningEffort,
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop, ChatCompletionTool as OpenAIChatCompletionTool,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+	ChatCompletionToolChoiceObject as OpenAIChatCompletionToolChoiceObject,
+	UserMessageContent as OpenAIUserMessageContent,
+	UserMessageContentPart as OpenAIUserMessageContentPart,
+};
+
+use crate::anthropic::v1::messages::request::{
+	ImageSource as AnthropicImageSource, Message as AnthropicMessage,
+	MessageContent as AnthropicMessageContent, MessageContentBlock as AnthropicMessageContentBlock,
+	MessageRole as AnthropicMessageRole, MessagesMetadata as AnthropicMessagesMetadata,
+	MessagesRequest as AnthropicMessagesRequest, MessagesSystem as AnthropicMessagesSystem,
+	Thinking as AnthropicThinking, Tool as AnthropicTool, ToolChoice as AnthropicToolChoice,
+	ToolResultContent as AnthropicToolResultContent,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_anthropic_v1(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		let mut system = Vec::<String>::new();
+		let mut messages = Vec::<AnthropicMessage>::new();
+
+		for message in self.messages.clone() {
+			let (role, blocks) = match message {
+				OpenAIChatCompletionMessage::SystemMessage { content, .. } => {
+					system.push(content);
+					continue;
+				},
+				OpenAIChatCompletionMessage::UserMessage { content, .. } => (
+					AnthropicMessageRole::User,
+					match content {
+						OpenAIUserMessageContent::TextContent(text) =>
+							vec![AnthropicMessageContentBlock::TextBlock { text }],
+						OpenAIUserMessageContent::ArrayContentParts(parts) => parts
+							.into_iter()
+							.map(|part| match part {
+								OpenAIUserMessageContentPart::TextContentPart { text } =>
+									AnthropicMessageContentBlock::TextBlock { text },
+								OpenAIUserMessageContentPart::ImageContentPart { image_url } =>
+									AnthropicMessageContentBlock::ImageBlock {
+										source: image_source(image_url.url),
+									},
+							})
+							.collect(),
+					},
+				),
+				OpenAIChatCompletionMessage::AssistantMessage { content, tool_calls, .. } => {
+					let mut blocks = Vec::new();
+					if let Some(text) = content.filter(|text| !text.is_empty()) {
+						blocks.push(AnthropicMessageContentBlock::TextBlock { text });
+					}
+					for call in tool_calls.unwrap_or_default() {
+						let input = call.function.arguments_object().ok_or_else(|| {
+							TransformationError::InvalidToolCallArguments {
+								tool_call_id: call.id.clone(),
+							}
+						})?;
+						blocks.push(AnthropicMessageContentBlock::ToolUseBlock {
+							id: call.id,
+							name: call.function.name,
+							input,
+						});
+					}
+					(AnthropicMessageRole::Assistant, blocks)
+				},
+				OpenAIChatCompletionMessage::ToolMessage { content, tool_call_id } => (
+					AnthropicMessageRole::User,
+					vec![AnthropicMessageContentBlock::ToolResultBlock {
+						tool_use_id: tool_call_id,
+						content: Some(AnthropicToolResultContent::TextContent(content)),
+						is_error: None,
+					}],
+				),
+			};
+
+			if blocks.is_empty() {
+				continue;
+			}
+
+			// Anthropic expects alternating roles, so consecutive messages with the same role
+			// (e.g. several tool results) are merged into a single turn.
+			match messages.last_mut() {
+				Some(AnthropicMessage {
+					role: last_role,
+					content: AnthropicMessageContent::BlocksContent(last_blocks),
+				}) if *last_role == role => last_blocks.extend(blocks),
+				_ => messages.push(AnthropicMessage {
+					role,
+					content: AnthropicMessageContent::BlocksContent(blocks),
+				}),
+			}
+		}
+
+		let (tool_choice, tool_choice_loss) = match self.tool_choice.clone() {
+			None => (None, None),
+			Some(OpenAIChatCompletionToolChoice::StringChoice(value)) => match value.as_str() {
+				"none" => (Some(AnthropicToolChoice::NoneChoice), None),
+				"auto" => (
+					Some(AnthropicToolChoice::AutoChoice { disable_parallel_tool_use: None }),
+					None,
+				),
+				"required" =>
+					(Some(AnthropicToolChoice::AnyChoice { disable_parallel_tool_use: None }), None),
+				_ => (None, Some(OpenAIChatCompletionToolChoice::StringChoice(value))),
+			},
+			Some(OpenAIChatCompletionToolChoice::FunctionChoice(
+				OpenAIChatCompletionToolChoiceObject::FunctionTool { function },
+			)) => (
+				Some(AnthropicToolChoice::NamedChoice {
+					name: function.name,
+					disable_parallel_tool_use: None,
+				}),
+				None,
+			),
+		};
+
+		let max_tokens = self.max_tokens.unwrap_or(context.default_max_tokens);
+		// The reasoning effort of the request takes precedence over the thinking configured on
+		// the connection.
+		let thinking = match &self.reasoning_effort {
+			Some(effort) => Some(thinking(effort, max_tokens)),
+			None => context.thinking,
+		};
+		let thinking_enabled = match thinking {
+			Some(AnthropicThinking::Enabled { budget_tokens }) => {
+				if budget_tokens < 1024 {
+					return Err(TransformationError::ThinkingBudgetBelowMinimum { budget_tokens });
+				}
+				// The thinking tokens count towards `max_tokens`, which has to leave room for the
+				// answer.
+				if budget_tokens >= max_tokens {
+					return Err(TransformationError::ThinkingBudgetExceedsMaxTokens {
+						budget_tokens,
+						max_tokens,
+					});
+				}
+				// Anthropic requires the thinking blocks of the assistant turn that called the
+				// tools when the request continues a tool use loop, and OpenAI messages can't carry
+				// them.
+				if let Some(tool_call_id) = pending_tool_result(&messages) {
+					return Err(TransformationError::MissingThinkingBlock { tool_call_id });
+				}
+				true
+			},
+			_ => false,
+		};
+
+		// Extended thinking only works with the model deciding on tool use by itself.
+		let (tool_choice, tool_choice_loss) = match tool_choice {
+			Some(
+				AnthropicToolChoice::AnyChoice { .. } | AnthropicToolChoice::NamedChoice { .. },
+			) if thinking_enabled => (None, self.tool_choice.clone()),
+			_ => (tool_choice, tool_choice_loss),
+		};
+
+		Ok(Transformation {
+			request: AnthropicMessagesRequest {
+				model: self.model.clone(),
+				messages,
+				max_tokens,
+				system: if system.is_empty() {
+					None
+				} else {
+					Some(AnthropicMessagesSystem::TextSystem(system.join("\n\n")))
+				},
+				metadata: self
+					.user
+					.clone()
+					.map(|user| AnthropicMessagesMetadata { user_id: Some(user) }),
+				stop_sequences: self.stop.clone().map(|stop| match stop {
+					OpenAIChatCompletionStop::StringStop(v) => vec![v],
+					OpenAIChatCompletionStop::ArrayStop(v) => v,
+				}),
+				stream: self.stream,
+				// Anthropic only accepts temperatures up to 1, while OpenAI goes up to 2, and
+				// doesn't accept any temperature with extended thinking.
+				temperature: self
+					.temperature
+					.filter(|_| !thinking_enabled)
+					.map(|temperature| temperature.min(1.0)),
+				// Anthropic doesn't accept `top_p` or `top_k` with extended thinking either.
+				top_p: self.top_p.filter(|_| !thinking_enabled),
+				top_k: context.top_k.filter(|_| !thinking_enabled),
+				tools: self.tools.clone().map(|tls| {
+					tls.into_iter()
+						.map(|tool| match tool {
+							OpenAIChatCompletionTool::FunctionTool { function } => AnthropicTool {
+								name: function.name,
+								description: function.description,
+								// Anthropic requires a schema, OpenAI defaults to a function
+								// without parameters.
+								input_schema: function.parameters.unwrap_or_else(
+									|| serde_json::json!({ "type": "object", "properties": {} }),
+								),
+							},
+						})
+						.collect()
+				}),
+				tool_choice,
+				thinking,
+			},
+			loss: TransformationLoss {
+				n: self.n,
+				temperature: self
+					.temperature
+					.filter(|temperature| thinking_enabled || *temperature > 1.0),
+				top_p: self.top_p.filter(|_| thinking_enabled),
+				top_k: context.top_k.filter(|_| thinking_enabled),
+				frequency_penalty: self.frequency_penalty,
+				presence_penalty: self.presence_penalty,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				seed: self.seed,
+				response_format: self.response_format.clone(),
+				logit_bias: self.logit_bias.clone(),
+				tool_choice: tool_choice_loss,
+			},
+		})
+	}
+}
+
+/// Anthropic takes a thinking budget instead of an effort, so the budget is derived as a share of
+/// `max_tokens`, which the thinking tokens count towards, with Anthropic's minimum of 1024 tokens.
+fn thinking(effort: &OpenAIChatCompletionReasoningEffort, max_tokens: u64) -> AnthropicThinking {
+	let percentage = match effort {
+		OpenAIChatCompletionReasoningEffort::Minimal => return AnthropicThinking::Disabled,
+		OpenAIChatCompletionReasoningEffort::Low => 20,
+		OpenAIChatCompletionReasoningEffort::Medium => 50,
+		OpenAIChatCompletionReasoningEffort::High => 80,
+	};
+	AnthropicThinking::Enabled {
+		budget_tokens: (max_tokens.saturating_mul(percentage) / 100).max(1024),
+	}
+}
+
+/// The id of the first tool call answered by the last message, when the request continues a tool
+/// use loop.
+fn pending_tool_result(messages: &[AnthropicMessage]) -> Option<String> {
+	match messages.last() {
+		Some(AnthropicMessage {
+			role: AnthropicMessageRole::User,
+			content: AnthropicMessageContent::BlocksContent(blocks),
+		}) => blocks.iter().find_map(|block| match block {
+			AnthropicMessageContentBlock::ToolResultBlock { tool_use_id, .. } =>
+				Some(tool_use_id.clone()),
+			_ => None,
+		}),
+		_ => None,
+	}
+}
+
+/// OpenAI accepts both regular URLs and base64 data URLs (`data:image/png;base64,...`), while
+/// Anthropic has a dedicated source type for each.
+fn image_source(url: String) -> AnthropicImageSource {
+	url.strip_prefix("data:")
+		.and_then(|data_url| data_url.split_once(";base64,"))
+		.map(|(media_type, data)| AnthropicImageSource::Base64Source {
+			media_type: media_type.to_string(),
+			data: data.to_string(),
+		})
+		.unwrap_or(AnthropicImageSource::UrlSource { url })
+}
+
+pub struct TransformationLoss {
+	pub n: Option<u64>,
+	/// The requested temperature, when it was above the maximum of 1 and got clamped, or when it
+	/// was dropped because extended thinking is enabled.
+	pub temperature: Option<f64>,
+	/// Set when dropped because extended thinking is enabled.
+	pub top_p: Option<f64>,
+	/// Set when dropped because extended thinking is enabled.
+	pub top_k: Option<u64>,
+	pub frequency_penalty: Option<f64>,
+	pub presence_penalty: Option<f64>,
+	pub logprobs: Option<bool>,
+	pub top_logprobs: Option<i64>,
+	pub seed: Option<i64>,
+	pub response_format: Option<serde_json::Value>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	/// Set when the tool choice has no Anthropic equivalent, or forces a tool call while extended
+	/// thinking is enabled.
+	pub tool_choice: Option<OpenAIChatCompletionToolChoice>,
+}
+
+pub struct TransformationContext {
+	/// Anthropic requires `max_tokens`, so this is used when the request doesn't set it.
+	pub default_max_tokens: u64,
+	pub top_k: Option<u64>,
+	/// Extended thinking, usually set on the connection for reasoning models. A `reasoning_effort`
+	/// on the request overrides it.
+	pub thinking: Option<AnthropicThinking>,
+}
+
+pub struct Transformation {
+	pub request: AnthropicMessagesRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// The arguments of an assistant tool call are not a valid JSON object.
+	InvalidToolCallArguments { tool_call_id: String },
+	/// The thinking budget is below Anthropic's minimum of 1024 tokens.
+	ThinkingBudgetBelowMinimum { budget_tokens: u64 },
+	/// The thinking budget doesn't leave room for the answer within `max_tokens`.
+	ThinkingBudgetExceedsMaxTokens { budget_tokens: u64, max_tokens: u64 },
+	/// The request continues a tool use loop with extended thinking, which requires the thinking
+	/// blocks of the assistant turn that OpenAI messages can't carry.
+	MissingThinkingBlock { tool_call_id: String },
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::InvalidToolCallArguments { tool_call_id } =>
+				write!(f, "arguments of tool call `{tool_call_id}` are not a valid JSON object"),
+			TransformationError::ThinkingBudgetBelowMinimum { budget_tokens } => write!(
+				f,
+				"thinking budget of {budget_tokens} tokens is below the minimum of 1024 tokens"
+			),
+			TransformationError::ThinkingBudgetExceedsMaxTokens { budget_tokens, max_tokens } =>
+				write!(
+				f,
+				"thinking budget of {budget_tokens} tokens is not below max_tokens of {max_tokens}"
+			),
+			TransformationError::MissingThinkingBlock { tool_call_id } => write!(
+				f,
+				"result of tool call `{tool_call_id}` requires the thinking blocks of the assistant turn"
+			),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	fn fx_context() -> TransformationContext {
+		TransformationContext { default_max_tokens: 4096, top_k: None, thinking: None }
+	}
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "Hello!" }
+		  ],
+		  "n": 2,
+		  "seed": 42,
+		  "stop": "END",
+		  "user": "user-1234",
+		  "temperature": 0.5
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context())?;
+
+		assert_eq!(data.request.model, "claude-3-5-sonnet-20241022");
+		assert_eq!(data.request.max_tokens, 4096);
+		assert_eq!(
+			data.request.system,
+			Some(AnthropicMessagesSystem::TextSystem("You are a helpful assistant.".to_string()))
+		);
+		assert_eq!(data.request.messages.len(), 1);
+		assert_eq!(data.request.stop_sequences, Some(vec!["END".to_string()]));
+		assert_eq!(
+			data.request.metadata,
+			Some(AnthropicMessagesMetadata { user_id: Some("user-1234".to_string()) })
+		);
+		assert_eq!(data.request.temperature, Some(0.5));
+		assert_eq!(data.loss.temperature, None);
+
+		// Check if the parameters Anthropic can't honor were passed to the loss object.
+		assert_eq!(data.loss.n, Some(2));
+		assert_eq!(data.loss.seed, Some(42));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_messages_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [
+			{ "role": "user", "content": "What's the weather in Boston and Paris?" },
+			{
+			  "role": "assistant",
+			  "content": null,
+			  "tool_calls": [
+				{ "id": "call_1", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\": \"Boston\"}" } },
+				{ "id": "call_2", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\": \"Paris\"}" } }
+			  ]
+			},
+			{ "role": "tool", "content": "10 degrees", "tool_call_id": "call_1" },
+			{ "role": "tool", "content": "15 degrees", "tool_call_id": "call_2" }
+		  ],
+		  "tools": [
+			{ "type": "function", "function": { "name": "get_weather" } }
+		  ],
+		  "tool_choice": "required",
+		  "max_tokens": 100
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context())?;
+
+		assert_eq!(data.request.max_tokens, 100);
+		assert_eq!(
+			data.request.tool_choice,
+			Some(AnthropicToolChoice::AnyChoice { disable_parallel_tool_use: None })
+		);
+		assert_eq!(
+			data.request.tools.unwrap()[0].input_schema,
+			json!({ "type": "object", "properties": {} })
+		);
+
+		// Check if the tool results were merged into a single user turn.
+		assert_eq!(data.request.messages.len(), 3);
+		assert_eq!(
+			data.request.messages[1].content,
+			AnthropicMessageContent::BlocksContent(vec![
+				AnthropicMessageContentBlock::ToolUseBlock {
+					id: "call_1".to_string(),
+					name: "get_weather".to_string(),
+					input: json!({ "location": "Boston" })
+				},
+				AnthropicMessageContentBlock::ToolUseBlock {
+					id: "call_2".to_string(),
+					name: "get_weather".to_string(),
+					input: json!({ "location": "Paris" })
+				},
+			])
+		);
+		assert_eq!(data.request.messages[2].role, AnthropicMessageRole::User);
+		assert_eq!(
+			data.request.messages[2].content,
+			AnthropicMessageContent::BlocksContent(vec![
+				AnthropicMessageContentBlock::ToolResultBlock {
+					tool_use_id: "call_1".to_string(),
+					content: Some(AnthropicToolResultContent::TextContent(
+						"10 degrees".to_string()
+					)),
+					is_error: None
+				},
+				AnthropicMessageContentBlock::ToolResultBlock {
+					tool_use_id: "call_2".to_string(),
+					content: Some(AnthropicToolResultContent::TextContent(
+						"15 degrees".to_string()
+					)),
+					is_error: None
+				},
+			])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_image_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [
+			{
+			  "role": "user",
+			  "content": [
+				{ "type": "image_url", "image_url": { "url": "data:image/png;base64,iVBORw0KGgo=" } },
+				{ "type": "image_url", "image_url": { "url": "https://example.com/image.jpg", "detail": "high" } }
+			  ]
+			}
+		  ]
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context())?;
+
+		assert_eq!(
+			data.request.messages[0].content,
+			AnthropicMessageContent::BlocksContent(vec![
+				AnthropicMessageContentBlock::ImageBlock {
+					source: AnthropicImageSource::Base64Source {
+						media_type: "image/png".to_string(),
+						data: "iVBORw0KGgo=".to_string()
+					}
+				},
+				AnthropicMessageContentBlock::ImageBlock {
+					source: AnthropicImageSource::UrlSource {
+						url: "https://example.com/image.jpg".to_string()
+					}
+				},
+			])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_high_temperature_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [{ "role": "user", "content": "Hello!" }],
+		  "temperature": 1.8
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context())?;
+
+		assert_eq!(data.request.temperature, Some(1.0));
+
+		// Check if the requested temperature was passed to the loss object.
+		assert_eq!(data.loss.temperature, Some(1.8));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_thinking_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-7-sonnet-20250219",
+		  "messages": [{ "role": "user", "content": "Are there an infinite number of prime numbers such that n mod 4 == 3?" }],
+		  "max_tokens": 16000,
+		  "temperature": 0.7
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(TransformationContext {
+			thinking: Some(AnthropicThinking::Enabled { budget_tokens: 10000 }),
+			..fx_context()
+		})?;
+
+		assert_eq!(
+			data.request.thinking,
+			Some(AnthropicThinking::Enabled { budget_tokens: 10000 })
+		);
+		assert_eq!(data.request.temperature, None);
+
+		// Check if the temperature, which can't be combined with thinking, was passed to the loss
+		// object.
+		assert_eq!(data.loss.temperature, Some(0.7));
+
+		let data = fx_request.to_anthropic_v1(TransformationContext {
+			thinking: Some(AnthropicThinking::Enabled { budget_tokens: 16000 }),
+			..fx_context()
+		});
+
+		assert_eq!(
+			data.err(),
+			Some(TransformationError::ThinkingBudgetExceedsMaxTokens {
+				budget_tokens: 16000,
+				max_tokens: 16000
+			})
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_reasoning_effort_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-7-sonnet-20250219",
+		  "messages": [{ "role": "user", "content": "What's the weather like in Boston today?" }],
+		  "max_tokens": 16000,
+		  "reasoning_effort": "medium",
+		  "top_p": 0.9,
+		  "tools": [{ "type": "function", "function": { "name": "get_weather" } }],
+		  "tool_choice": "required"
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(TransformationContext {
+			top_k: Some(40),
+			thinking: Some(AnthropicThinking::Disabled),
+			..fx_context()
+		})?;
+
+		assert_eq!(data.request.thinking, Some(AnthropicThinking::Enabled { budget_tokens: 8000 }));
+		assert_eq!(data.request.top_p, None);
+		assert_eq!(data.request.top_k, None);
+		assert_eq!(data.request.tool_choice, None);
+
+		// Check if the parameters that can't be combined with thinking were passed to the loss
+		// object.
+		assert_eq!(data.loss.top_p, Some(0.9));
+		assert_eq!(data.loss.top_k, Some(40));
+		assert_eq!(
+			data.loss.tool_choice,
+			Some(OpenAIChatCompletionToolChoice::StringChoice("required".to_string()))
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_minimal_reasoning_effort_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-7-sonnet-20250219",
+		  "messages": [{ "role": "user", "content": "Hello!" }],
+		  "reasoning_effort": "minimal",
+		  "top_p": 0.9
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(TransformationContext {
+			thinking: Some(AnthropicThinking::Enabled { budget_tokens: 2048 }),
+			..fx_context()
+		})?;
+
+		assert_eq!(data.request.thinking, Some(AnthropicThinking::Disabled));
+		assert_eq!(data.request.top_p, Some(0.9));
+		assert_eq!(data.loss.top_p, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_thinking_budget_below_minimum_request_transform_fail() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-7-sonnet-20250219",
+		  "messages": [{ "role": "user", "content": "Hello!" }]
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(TransformationContext {
+			thinking: Some(AnthropicThinking::Enabled { budget_tokens: 512 }),
+			..fx_context()
+		});
+
+		assert_eq!(
+			data.err(),
+			Some(TransformationError::ThinkingBudgetBelowMinimum { budget_tokens: 512 })
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_thinking_tool_result_request_transform_fail() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-7-sonnet-20250219",
+		  "messages": [
+			{ "role": "user", "content": "What's the weather like in Boston today?" },
+			{
+			  "role": "assistant",
+			  "tool_calls": [
+				{ "id": "call_1", "type": "function", "function": { "name": "get_weather", "arguments": "{}" } }
+			  ]
+			},
+			{ "role": "tool", "tool_call_id": "call_1", "content": "Sunny" }
+		  ],
+		  "reasoning_effort": "high"
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context());
+
+		assert_eq!(
+			data.err(),
+			Some(TransformationError::MissingThinkingBlock { tool_call_id: "call_1".to_string() })
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_empty_tool_arguments_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [
+			{
+			  "role": "assistant",
+			  "tool_calls": [
+				{ "id": "call_1", "type": "function", "function": { "name": "get_time", "arguments": "" } }
+			  ]
+			}
+		  ]
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context())?;
+
+		assert_eq!(
+			data.request.messages[0].content,
+			AnthropicMessageContent::BlocksContent(vec![
+				AnthropicMessageContentBlock::ToolUseBlock {
+					id: "call_1".to_string(),
+					name: "get_time".to_string(),
+					input: json!({})
+				}
+			])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_non_object_tool_arguments_request_transform_fail() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [
+			{
+			  "role": "assistant",
+			  "tool_calls": [
+				{ "id": "call_1", "type": "function", "function": { "name": "get_weather", "arguments": "[\"Boston\"]" } }
+			  ]
+			}
+		  ]
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context());
+
+		assert_eq!(
+			data.err(),
+			Some(TransformationError::InvalidToolCallArguments {
+				tool_call_id: "call_1".to_string()
+			})
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_invalid_tool_arguments_request_transform_fail() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "claude-3-5-sonnet-20241022",
+		  "messages": [
+			{
+			  "role": "assistant",
+			  "tool_calls": [
+				{ "id": "call_1", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\"" } }
+			  ]
+			}
+		  ]
+		}))?;
+
+		let data = fx_request.to_anthropic_v1(fx_context());
+
+		assert_eq!(
+			data.err(),
+			Some(TransformationError::InvalidToolCallArguments {
+				tool_call_id: "call_1".to_string()
+			})
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
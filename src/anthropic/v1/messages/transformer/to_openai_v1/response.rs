@@ -0,0 +1,441 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as OpenAIChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceDelta as OpenAIChatCompletionChunkResponseChoiceDelta,
+	ChatCompletionChunkResponseChoiceFunctionToolCall as OpenAIChatCompletionChunkResponseChoiceFunctionToolCall,
+	ChatCompletionChunkResponseChoiceToolCall as OpenAIChatCompletionChunkResponseChoiceToolCall,
+	ChatCompletionChunkResponseChoiceToolCallType as OpenAIChatCompletionChunkResponseChoiceToolCallType,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionObjectResponseChoice as OpenAIChatCompletionObjectResponseChoice,
+	ChatCompletionObjectResponseChoiceMessage as OpenAIChatCompletionObjectResponseChoiceMessage,
+	ChatCompletionObjectResponseChoiceToolCall as OpenAIChatCompletionObjectResponseChoiceToolCall,
+	ChatCompletionResponseChoiceFunctionToolCall as OpenAIChatCompletionResponseChoiceFunctionToolCall,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+	ChatCompletionResponseUsagePromptTokensDetails as OpenAIChatCompletionResponseUsagePromptTokensDetails,
+};
+
+use crate::anthropic::v1::messages::response::{
+	ContentBlockDelta as AnthropicContentBlockDelta, MessagesResponse as AnthropicMessagesResponse,
+	MessagesResponseUsage as AnthropicMessagesResponseUsage,
+	MessagesStreamEvent as AnthropicMessagesStreamEvent,
+	ResponseContentBlock as AnthropicResponseContentBlock,
+};
+
+// region:    --- Object Response
+impl AnthropicMessagesResponse {
+	pub fn to_openai_v1(&self, context: TransformationContext) -> Transformation {
+		let text: Vec<String> = self
+			.content
+			.iter()
+			.filter_map(|block| match block {
+				AnthropicResponseContentBlock::TextBlock { text } => Some(text.clone()),
+				_ => None,
+			})
+			.collect();
+
+		let tool_calls: Vec<OpenAIChatCompletionObjectResponseChoiceToolCall> = self
+			.content
+			.iter()
+			.filter_map(|block| match block {
+				AnthropicResponseContentBlock::ToolUseBlock { id, name, input } =>
+					Some(OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+						id: id.clone(),
+						function: OpenAIChatCompletionResponseChoiceFunctionToolCall {
+							name: name.clone(),
+							arguments: input.to_string(),
+						},
+					}),
+				_ => None,
+			})
+			.collect();
+
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: vec![OpenAIChatCompletionObjectResponseChoice {
+					finish_reason: finish_reason(self.stop_reason.as_deref()),
+					index: 0,
+					message: OpenAIChatCompletionObjectResponseChoiceMessage {
+						content: if text.is_empty() { None } else { Some(text.concat()) },
+						role: "assistant".to_string(),
+						tool_calls: if tool_calls.is_empty() { None } else { Some(tool_calls) },
+						annotations: None,
+					},
+					logprobs: None,
+				}],
+				created: context.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: usage(&self.usage, self.usage.output_tokens),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				thinking: self
+					.content
+					.iter()
+					.filter(|block| {
+						matches!(
+							block,
+							AnthropicResponseContentBlock::ThinkingBlock { .. }
+								| AnthropicResponseContentBlock::RedactedThinkingBlock { .. }
+						)
+					})
+					.cloned()
+					.collect(),
+				stop_sequence: self.stop_sequence.clone(),
+				cache_creation_input_tokens: self.usage.cache_creation_input_tokens,
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	/// Thinking and redacted thinking blocks, which have no OpenAI counterpart.
+	pub thinking: Vec<AnthropicResponseContentBlock>,
+	/// The stop sequence that was hit, OpenAI only reports that generation stopped.
+	pub stop_sequence: Option<String>,
+	/// Tokens written to the prompt cache, OpenAI only reports the tokens read from it in
+	/// `prompt_tokens_details.cached_tokens`. They are still counted in `prompt_tokens`.
+	pub cache_creation_input_tokens: Option<u64>,
+}
+
+pub struct TransformationContext {
+	/// Anthropic doesn't return a creation timestamp, so it has to be provided by the caller.
+	pub created: u64,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Events
+impl AnthropicMessagesStreamEvent {
+	/// Anthropic spreads the information of an OpenAI chunk across several events, so the context
+	/// has to be shared by every event of the same stream. Returns `None` for events that have no
+	/// OpenAI counterpart (`ping`, `content_block_stop`, thinking blocks). `error` events also
+	/// return `None` and should be surfaced through the error transformer instead.
+	pub fn to_openai_v1(
+		&self,
+		context: &mut StreamTransformationContext,
+	) -> Option<OpenAIChatCompletionChunkResponse> {
+		match self {
+			AnthropicMessagesStreamEvent::MessageStart { message } => {
+				context.id = message.id.clone();
+				context.model = message.model.clone();
+				context.usage = Some(message.usage.clone());
+
+				Some(context.chunk(
+					Some(OpenAIChatCompletionChunkResponseChoiceDelta {
+						content: Some("".to_string()),
+						role: Some("assistant".to_string()),
+						tool_calls: None,
+						annotations: None,
+					}),
+					None,
+				))
+			},
+			AnthropicMessagesStreamEvent::ContentBlockStart { index, content_block } =>
+				match content_block {
+					AnthropicResponseContentBlock::TextBlock { text } if !text.is_empty() =>
+						Some(context.chunk(
+							Some(OpenAIChatCompletionChunkResponseChoiceDelta {
+								content: Some(text.clone()),
+								..Default::default()
+							}),
+							None,
+						)),
+					AnthropicResponseContentBlock::ToolUseBlock { id, name, .. } => {
+						context.tool_call_blocks.push(*index);
+
+						let tool_call = OpenAIChatCompletionChunkResponseChoiceToolCall {
+							index: context.tool_call_blocks.len() as u64 - 1,
+							id: Some(id.clone()),
+							r#type: Some(
+								OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType,
+							),
+							function: Some(
+								OpenAIChatCompletionChunkResponseChoiceFunctionToolCall {
+									name: Some(name.clone()),
+									arguments: Some("".to_string()),
+								},
+							),
+						};
+
+						Some(context.tool_call_chunk(tool_call))
+					},
+					_ => None,
+				},
+			AnthropicMessagesStreamEvent::ContentBlockDelta { index, delta } => match delta {
+				AnthropicContentBlockDelta::TextDelta { text } => Some(context.chunk(
+					Some(OpenAIChatCompletionChunkResponseChoiceDelta {
+						content: Some(text.clone()),
+						..Default::default()
+					}),
+					None,
+				)),
+				AnthropicContentBlockDelta::InputJsonDelta { partial_json } => {
+					let tool_call_index =
+						context.tool_call_blocks.iter().position(|block| block == index)?;
+
+					let tool_call = OpenAIChatCompletionChunkResponseChoiceToolCall {
+						index: tool_call_index as u64,
+						id: None,
+						r#type: None,
+						function: Some(OpenAIChatCompletionChunkResponseChoiceFunctionToolCall {
+							name: None,
+							arguments: Some(partial_json.clone()),
+						}),
+					};
+
+					Some(context.tool_call_chunk(tool_call))
+				},
+				_ => None,
+			},
+			AnthropicMessagesStreamEvent::MessageDelta { delta, usage } => {
+				if let Some(current) = context.usage.as_mut() {
+					current.output_tokens = usage.output_tokens;
+				}
+
+				let mut chunk = context
+					.chunk(Some(OpenAIChatCompletionChunkResponseChoiceDelta::default()), None);
+				chunk.choices[0].finish_reason = Some(finish_reason(delta.stop_reason.as_deref()));
+				Some(chunk)
+			},
+			AnthropicMessagesStreamEvent::MessageStop => {
+				if !context.include_usage {
+					return None;
+				}
+				let usage =
+					context.usage.as_ref().map(|current| usage(current, current.output_tokens));
+				Some(context.chunk(None, usage))
+			},
+			AnthropicMessagesStreamEvent::ContentBlockStop { .. }
+			| AnthropicMessagesStreamEvent::Ping
+			| AnthropicMessagesStreamEvent::Error { .. } => None,
+		}
+	}
+}
+
+pub struct StreamTransformationContext {
+	/// Anthropic doesn't return a creation timestamp, so it has to be provided by the caller.
+	pub created: u64,
+	/// Emit a final chunk with the usage, like OpenAI does with `stream_options.include_usage`.
+	pub include_usage: bool,
+	id: String,
+	model: String,
+	usage: Option<AnthropicMessagesResponseUsage>,
+	/// Content block indexes of the tool use blocks, in the order OpenAI numbers the tool calls.
+	tool_call_blocks: Vec<u64>,
+}
+
+impl StreamTransformationContext {
+	pub fn new(created: u64, include_usage: bool) -> Self {
+		StreamTransformationContext {
+			created,
+			include_usage,
+			id: "".to_string(),
+			model: "".to_string(),
+			usage: None,
+			tool_call_blocks: vec![],
+		}
+	}
+
+	/// Tokens written to the prompt cache, reported in the `message_start` event. OpenAI only
+	/// reports the tokens read from it in `prompt_tokens_details.cached_tokens`.
+	pub fn cache_creation_input_tokens(&self) -> Option<u64> {
+		self.usage.as_ref().and_then(|usage| usage.cache_creation_input_tokens)
+	}
+
+	fn tool_call_chunk(
+		&self,
+		tool_call: OpenAIChatCompletionChunkResponseChoiceToolCall,
+	) -> OpenAIChatCompletionChunkResponse {
+		self.chunk(
+			Some(OpenAIChatCompletionChunkResponseChoiceDelta {
+				tool_calls: Some(vec![tool_call]),
+				..Default::default()
+			}),
+			None,
+		)
+	}
+
+	fn chunk(
+		&self,
+		delta: Option<OpenAIChatCompletionChunkResponseChoiceDelta>,
+		usage: Option<OpenAIChatCompletionResponseUsage>,
+	) -> OpenAIChatCompletionChunkResponse {
+		OpenAIChatCompletionChunkResponse {
+			id: self.id.clone(),
+			choices: delta
+				.map(|delta| {
+					vec![OpenAIChatCompletionChunkResponseChoice {
+						finish_reason: None,
+						index: 0,
+						delta,
+						logprobs: None,
+					}]
+				})
+				.unwrap_or_default(),
+			created: self.created,
+			model: self.model.clone(),
+			system_fingerprint: None,
+			object: "chat.completion.chunk".to_string(),
+			usage,
+			service_tier: None,
+		}
+	}
+}
+// endregion: --- Stream Events
+
+fn finish_reason(stop_reason: Option<&str>) -> String {
+	match stop_reason {
+		Some("max_tokens") => "length",
+		Some("tool_use") => "tool_calls",
+		Some("refusal") => "content_filter",
+		// end_turn, stop_sequence and pause_turn
+		_ => "stop",
+	}
+	.to_string()
+}
+
+/// OpenAI counts cached tokens as part of the prompt tokens, Anthropic reports them separately.
+/// Tokens written to the cache are counted too, and exposed separately by the transformations.
+fn usage(
+	usage: &AnthropicMessagesResponseUsage,
+	output_tokens: u64,
+) -> OpenAIChatCompletionResponseUsage {
+	let prompt_tokens = usage.input_tokens
+		+ usage.cache_creation_input_tokens.unwrap_or(0)
+		+ usage.cache_read_input_tokens.unwrap_or(0);
+
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: output_tokens,
+		prompt_tokens,
+		total_tokens: prompt_tokens + output_tokens,
+		prompt_tokens_details: usage.cache_read_input_tokens.map(|cached_tokens| {
+			OpenAIChatCompletionResponseUsagePromptTokensDetails {
+				cached_tokens: Some(cached_tokens),
+			}
+		}),
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: AnthropicMessagesResponse = serde_json::from_value(json!({
+		  "id": "msg_01",
+		  "type": "message",
+		  "role": "assistant",
+		  "content": [
+			{ "type": "thinking", "thinking": "The user wants the weather.", "signature": "sig" },
+			{ "type": "text", "text": "Let me check." },
+			{ "type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": { "location": "Boston" } }
+		  ],
+		  "model": "claude-3-7-sonnet-20250219",
+		  "stop_reason": "tool_use",
+		  "stop_sequence": null,
+		  "usage": { "input_tokens": 10, "output_tokens": 20, "cache_creation_input_tokens": 3, "cache_read_input_tokens": 5 }
+		}))?;
+
+		let data = fx_response.to_openai_v1(TransformationContext { created: 1700000000 });
+
+		assert_eq!(data.response.id, "msg_01");
+		assert_eq!(data.response.created, 1700000000);
+		assert_eq!(data.response.choices[0].finish_reason, "tool_calls");
+		assert_eq!(data.response.choices[0].message.content, Some("Let me check.".to_string()));
+		assert_eq!(
+			data.response.choices[0].message.tool_calls,
+			Some(vec![OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+				id: "toolu_01".to_string(),
+				function: OpenAIChatCompletionResponseChoiceFunctionToolCall {
+					name: "get_weather".to_string(),
+					arguments: "{\"location\":\"Boston\"}".to_string()
+				},
+			}])
+		);
+		assert_eq!(
+			data.response.usage,
+			OpenAIChatCompletionResponseUsage {
+				completion_tokens: 20,
+				prompt_tokens: 18,
+				total_tokens: 38,
+				prompt_tokens_details: Some(OpenAIChatCompletionResponseUsagePromptTokensDetails {
+					cached_tokens: Some(5)
+				}),
+			}
+		);
+
+		// Check if the thinking block was passed to the loss object.
+		assert_eq!(data.loss.thinking.len(), 1);
+		// Check if the cache creation tokens were passed to the loss object.
+		assert_eq!(data.loss.cache_creation_input_tokens, Some(3));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stream_events_transform_ok() -> Result<()> {
+		let fx_events: Vec<AnthropicMessagesStreamEvent> = serde_json::from_value(json!([
+		  { "type": "message_start", "message": { "id": "msg_01", "type": "message", "role": "assistant", "content": [], "model": "claude-3-5-sonnet-20241022", "stop_reason": null, "stop_sequence": null, "usage": { "input_tokens": 10, "output_tokens": 1, "cache_creation_input_tokens": 4 } } },
+		  { "type": "content_block_start", "index": 0, "content_block": { "type": "text", "text": "" } },
+		  { "type": "ping" },
+		  { "type": "content_block_delta", "index": 0, "delta": { "type": "text_delta", "text": "Let me check." } },
+		  { "type": "content_block_stop", "index": 0 },
+		  { "type": "content_block_start", "index": 1, "content_block": { "type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {} } },
+		  { "type": "content_block_delta", "index": 1, "delta": { "type": "input_json_delta", "partial_json": "{\"location\": \"Boston\"}" } },
+		  { "type": "content_block_stop", "index": 1 },
+		  { "type": "message_delta", "delta": { "stop_reason": "tool_use", "stop_sequence": null }, "usage": { "output_tokens": 20 } },
+		  { "type": "message_stop" }
+		]))?;
+
+		let mut context = StreamTransformationContext::new(1700000000, true);
+		let data: Vec<OpenAIChatCompletionChunkResponse> =
+			fx_events.iter().filter_map(|event| event.to_openai_v1(&mut context)).collect();
+
+		assert_eq!(data.len(), 6);
+		assert!(data.iter().all(|chunk| chunk.id == "msg_01" && chunk.created == 1700000000));
+		assert_eq!(data[0].choices[0].delta.role, Some("assistant".to_string()));
+		assert_eq!(data[1].choices[0].delta.content, Some("Let me check.".to_string()));
+
+		let tool_call = data[2].choices[0].delta.tool_calls.clone().unwrap().remove(0);
+		assert_eq!(tool_call.index, 0);
+		assert_eq!(tool_call.id, Some("toolu_01".to_string()));
+
+		let tool_call = data[3].choices[0].delta.tool_calls.clone().unwrap().remove(0);
+		assert_eq!(tool_call.index, 0);
+		assert_eq!(
+			tool_call.function.unwrap().arguments,
+			Some("{\"location\": \"Boston\"}".to_string())
+		);
+
+		assert_eq!(data[4].choices[0].finish_reason, Some("tool_calls".to_string()));
+		assert!(data[5].choices.is_empty());
+		assert_eq!(
+			data[5].usage,
+			Some(OpenAIChatCompletionResponseUsage {
+				completion_tokens: 20,
+				prompt_tokens: 14,
+				total_tokens: 34,
+				prompt_tokens_details: None,
+			})
+		);
+		assert_eq!(context.cache_creation_input_tokens(), Some(4));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -29,11 +29,23 @@ pub struct ChatCompletionResponse {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionResponseUsage {
 	/// Number of tokens in the generated completion.
-	completion_tokens: u64,
+	pub completion_tokens: u64,
 	/// Number of tokens in the prompt.
-	prompt_tokens: u64,
+	pub prompt_tokens: u64,
 	/// Total number of tokens used in the request (prompt + completion).
-	total_tokens: u64,
+	pub total_tokens: u64,
+	/// Breakdown of tokens used in the prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_tokens_details: Option<ChatCompletionResponseUsagePromptTokensDetails>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsagePromptTokensDetails {
+	/// Cached tokens present in the prompt, which are included in `prompt_tokens` and billed at
+	/// a discounted rate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cached_tokens: Option<u64>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -52,17 +64,62 @@ pub struct ChatCompletionResponseChoice {
 	// A chat completion message generated by the model.
 	pub message: ChatCompletionResponseChoiceMessage,
 
-	/// Log probability information for the choice.
+	/// Information about the content filtering category, if it has been detected.
 	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
 	pub content_filter_results: Option<ChatCompletionResponseContentFilterResults>,
 }
 
+// region:    --- Content Filter Results
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionResponseContentFilterResults {
-	// TODO
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub sexual: Option<ContentFilterSeverityResult>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub violence: Option<ContentFilterSeverityResult>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub hate: Option<ContentFilterSeverityResult>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub self_harm: Option<ContentFilterSeverityResult>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub profanity: Option<ContentFilterDetectedResult>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub jailbreak: Option<ContentFilterDetectedResult>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub error: Option<ContentFilterError>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentFilterSeverityResult {
+	pub filtered: bool,
+	/// One of `safe`, `low`, `medium` or `high`.
+	pub severity: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentFilterDetectedResult {
+	pub filtered: bool,
+	pub detected: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentFilterError {
+	pub code: String,
+	pub message: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PromptFilterResult {
+	pub prompt_index: u64,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content_filter_results: Option<ChatCompletionResponseContentFilterResults>,
+}
+// endregion: --- Content Filter Results
+
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionResponseChoiceMessage {
@@ -111,6 +168,213 @@ pub enum ChatCompletionResponseChoiceToolCall {
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionResponseChoiceFunctionToolCall {
-	name: String,
-	arguments: String,
+	pub name: String,
+	pub arguments: String,
+}
+
+// region:    --- Chunk Response
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	/// A unique identifier for the chat completion. Empty for chunks that only carry prompt
+	/// filter results.
+	pub id: String,
+
+	/// A list of chat completion choices. Can be more than one if n is greater than 1.
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: u64,
+
+	/// The model used for the chat completion.
+	pub model: String,
+
+	/// The object type, which is chat.completion.chunk or empty for annotation-only chunks.
+	pub object: String,
+
+	/// This fingerprint represents the backend configuration that the model runs with.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system_fingerprint: Option<String>,
+
+	/// Content filtering results for the prompts in the request. Sent in its own chunk, before
+	/// any content.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_filter_results: Option<Vec<PromptFilterResult>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoice {
+	/// The reason the model stopped generating tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub finish_reason: Option<String>,
+
+	/// The index of the choice in the list of choices.
+	pub index: u64,
+
+	// A chat completion delta generated by streamed model responses.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub delta: ChatCompletionChunkResponseChoiceDelta,
+
+	/// Information about the content filtering category, if it has been detected.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content_filter_results: Option<ChatCompletionResponseContentFilterResults>,
 }
+
+#[derive(Debug, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoiceDelta {
+	/// The contents of the message
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content: Option<String>,
+	/// The role of the author of the message
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub role: Option<String>,
+	/// The tool calls generated by the model, such as function calls.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<Vec<ChatCompletionChunkResponseChoiceToolCall>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoiceToolCall {
+	/// The index of the tool call in the list of tool calls.
+	pub index: u64,
+	/// The ID of the tool call. Only sent in the first chunk of each tool call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub id: Option<String>,
+	/// The type of the tool. Only sent in the first chunk of each tool call.
+	#[cfg_attr(feature = "serde", serde(rename = "type", skip_serializing_if = "Option::is_none"))]
+	pub r#type: Option<ChatCompletionChunkResponseChoiceToolCallType>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub function: Option<ChatCompletionChunkResponseChoiceFunctionToolCall>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatCompletionChunkResponseChoiceToolCallType {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoiceFunctionToolCall {
+	/// The name of the function to call. Only sent in the first chunk of each tool call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub name: Option<String>,
+	/// A fragment of the arguments to call the function with, as generated by the model in JSON
+	/// format.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub arguments: Option<String>,
+}
+// endregion: --- Chunk Response
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_chunk_prompt_filter_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "choices": [],
+		  "created": 0,
+		  "id": "",
+		  "model": "",
+		  "object": "",
+		  "prompt_filter_results": [
+			{
+			  "prompt_index": 0,
+			  "content_filter_results": {
+				"hate": { "filtered": false, "severity": "safe" },
+				"jailbreak": { "filtered": false, "detected": false },
+				"self_harm": { "filtered": false, "severity": "safe" },
+				"sexual": { "filtered": false, "severity": "safe" },
+				"violence": { "filtered": false, "severity": "safe" }
+			  }
+			}
+		  ]
+		})
+		.to_string();
+
+		let data: ChatCompletionChunkResponse = serde_json::from_str(&fx_response).unwrap();
+
+		let results = data.prompt_filter_results.unwrap();
+		assert_eq!(
+			results[0].content_filter_results.as_ref().unwrap().jailbreak,
+			Some(ContentFilterDetectedResult { filtered: false, detected: false })
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_response_chunk_content_filter_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "choices": [
+			{
+			  "content_filter_results": {
+				"hate": { "filtered": false, "severity": "safe" },
+				"self_harm": { "filtered": false, "severity": "safe" },
+				"sexual": { "filtered": false, "severity": "safe" },
+				"violence": { "filtered": false, "severity": "safe" }
+			  },
+			  "delta": { "content": "Hello" },
+			  "finish_reason": null,
+			  "index": 0
+			}
+		  ],
+		  "created": 1694268190,
+		  "id": "chatcmpl-123",
+		  "model": "gpt-4o",
+		  "object": "chat.completion.chunk",
+		  "system_fingerprint": "fp_44709d6fcb"
+		})
+		.to_string();
+
+		let data: ChatCompletionChunkResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.choices[0].delta.content, Some("Hello".to_string()));
+		assert_eq!(
+			data.choices[0].content_filter_results.as_ref().unwrap().hate,
+			Some(ContentFilterSeverityResult { filtered: false, severity: "safe".to_string() })
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_response_chunk_without_delta_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "choices": [
+			{
+			  "content_filter_results": {},
+			  "finish_reason": null,
+			  "index": 0
+			}
+		  ],
+		  "created": 0,
+		  "id": "",
+		  "model": "",
+		  "object": ""
+		})
+		.to_string();
+
+		let data: ChatCompletionChunkResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.choices[0].delta, ChatCompletionChunkResponseChoiceDelta::default());
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -148,6 +148,7 @@ mod tests {
 			logit_bias: None,
 			tools: None,
 			tool_choice: None,
+			reasoning_effort: None,
 		};
 
 		let data = fx_request.to_azure_v2024_02_01(TransformationContext { data_sources: None });
@@ -167,11 +168,10 @@ mod tests {
 
 	#[test]
 	fn test_system_message_request_transform_ok() -> Result<()> {
-		let mut fx_messages = Vec::<OpenAIChatCompletionMessage>::new();
-		fx_messages.push(OpenAIChatCompletionMessage::SystemMessage {
+		let fx_messages = vec![OpenAIChatCompletionMessage::SystemMessage {
 			content: "hello".to_string(),
 			name: Some("my-name".to_string()),
-		});
+		}];
 
 		let fx_request = OpenAIChatCompletionRequest {
 			model: "my-model".to_string(),
@@ -192,6 +192,7 @@ mod tests {
 			logit_bias: None,
 			tools: None,
 			tool_choice: None,
+			reasoning_effort: None,
 		};
 
 		let data = fx_request.to_azure_v2024_02_01(TransformationContext { data_sources: None });
@@ -215,23 +216,24 @@ mod tests {
 
 	#[test]
 	fn test_user_message_request_transform_ok() -> Result<()> {
-		let mut fx_messages = Vec::<OpenAIChatCompletionMessage>::new();
-		fx_messages.push(OpenAIChatCompletionMessage::UserMessage {
-			name: None,
-			content: OpenAIUserMessageContent::TextContent("hello".to_string()),
-		});
-		fx_messages.push(OpenAIChatCompletionMessage::UserMessage {
-			name: None,
-			content: OpenAIUserMessageContent::ArrayContentParts(vec![
-				OpenAIUserMessageContentPart::TextContentPart { text: "part".to_string() },
-				OpenAIUserMessageContentPart::ImageContentPart {
-					image_url: ImageUrlContentPart {
-						url: "http://example.com".to_string(),
-						detail: Some("detail".to_string()),
+		let fx_messages = vec![
+			OpenAIChatCompletionMessage::UserMessage {
+				name: None,
+				content: OpenAIUserMessageContent::TextContent("hello".to_string()),
+			},
+			OpenAIChatCompletionMessage::UserMessage {
+				name: None,
+				content: OpenAIUserMessageContent::ArrayContentParts(vec![
+					OpenAIUserMessageContentPart::TextContentPart { text: "part".to_string() },
+					OpenAIUserMessageContentPart::ImageContentPart {
+						image_url: ImageUrlContentPart {
+							url: "http://example.com".to_string(),
+							detail: Some("detail".to_string()),
+						},
 					},
-				},
-			]),
-		});
+				]),
+			},
+		];
 
 		let fx_request = OpenAIChatCompletionRequest {
 			model: "my-model".to_string(),
@@ -252,6 +254,7 @@ mod tests {
 			logit_bias: None,
 			tools: None,
 			tool_choice: None,
+			reasoning_effort: None,
 		};
 
 		let data = fx_request.to_azure_v2024_02_01(TransformationContext { data_sources: None });
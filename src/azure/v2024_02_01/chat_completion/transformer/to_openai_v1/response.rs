@@ -1 +1,247 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as OpenAIChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceDelta as OpenAIChatCompletionChunkResponseChoiceDelta,
+	ChatCompletionChunkResponseChoiceFunctionToolCall as OpenAIChatCompletionChunkResponseChoiceFunctionToolCall,
+	ChatCompletionChunkResponseChoiceToolCall as OpenAIChatCompletionChunkResponseChoiceToolCall,
+	ChatCompletionChunkResponseChoiceToolCallType as OpenAIChatCompletionChunkResponseChoiceToolCallType,
+};
 
+use crate::azure::v2024_02_01::chat_completion::response::{
+	ChatCompletionChunkResponse as AzureChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as AzureChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceToolCallType as AzureChatCompletionChunkResponseChoiceToolCallType,
+	ChatCompletionResponseContentFilterResults as AzureChatCompletionResponseContentFilterResults,
+	PromptFilterResult as AzurePromptFilterResult,
+};
+
+impl AzureChatCompletionChunkResponse {
+	/// The response is `None` when the chunk only carries content filter annotations and the
+	/// context asks for those chunks to be stripped. The annotations still end up in the loss.
+	pub fn to_openai_v1(&self, context: TransformationContext) -> Transformation {
+		let stripped =
+			context.annotations == ContentFilterAnnotations::Strip && self.is_annotation_only();
+
+		Transformation {
+			response: (!stripped).then(|| OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices: self
+					.choices
+					.clone()
+					.into_iter()
+					.filter(|choice| context.annotations == ContentFilterAnnotations::Normalize || !choice.is_annotation_only())
+					.map(|choice| OpenAIChatCompletionChunkResponseChoice {
+						finish_reason: choice.finish_reason,
+						index: choice.index,
+						delta: OpenAIChatCompletionChunkResponseChoiceDelta {
+							content: choice.delta.content,
+							role: choice.delta.role,
+							tool_calls: choice.delta.tool_calls.map(|calls| {
+								calls
+									.into_iter()
+									.map(|call| OpenAIChatCompletionChunkResponseChoiceToolCall {
+										index: call.index,
+										id: call.id,
+										r#type: call.r#type.map(|r#type| match r#type {
+											AzureChatCompletionChunkResponseChoiceToolCallType::FunctionType => OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType,
+										}),
+										function: call.function.map(|function| OpenAIChatCompletionChunkResponseChoiceFunctionToolCall { name: function.name, arguments: function.arguments }),
+									})
+									.collect()
+							}),
+							annotations: None,
+						},
+						logprobs: None,
+					})
+					.collect(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: self.system_fingerprint.clone(),
+				// Annotation-only chunks are sent with an empty object type.
+				object: "chat.completion.chunk".to_string(),
+				usage: None,
+				service_tier: None,
+			}),
+			loss: TransformationLoss {
+				prompt_filter_results: self.prompt_filter_results.clone(),
+				content_filter_results: self
+					.choices
+					.iter()
+					.filter_map(|choice| choice.content_filter_results.clone().map(|results| (choice.index, results)))
+					.collect(),
+			},
+		}
+	}
+
+	fn is_annotation_only(&self) -> bool {
+		self.choices.iter().all(|choice| choice.is_annotation_only())
+	}
+}
+
+impl AzureChatCompletionChunkResponseChoice {
+	fn is_annotation_only(&self) -> bool {
+		self.finish_reason.is_none()
+			&& self.delta.content.is_none()
+			&& self.delta.role.is_none()
+			&& self.delta.tool_calls.is_none()
+	}
+}
+
+/// How content filter annotations that Azure interleaves with the stream are handled. The
+/// annotations themselves are always moved to the transformation loss, as OpenAI chunks have no
+/// place for them.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ContentFilterAnnotations {
+	/// Drop chunks and choices that only carry annotations.
+	Strip,
+	/// Keep every chunk and choice, emitting annotation-only ones as empty OpenAI chunks.
+	Normalize,
+}
+
+pub struct TransformationLoss {
+	pub prompt_filter_results: Option<Vec<AzurePromptFilterResult>>,
+	/// Content filter results keyed by choice index.
+	pub content_filter_results: Vec<(u64, AzureChatCompletionResponseContentFilterResults)>,
+}
+
+pub struct TransformationContext {
+	pub annotations: ContentFilterAnnotations,
+}
+
+pub struct Transformation {
+	pub response: Option<OpenAIChatCompletionChunkResponse>,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use crate::azure::v2024_02_01::chat_completion::response::{
+		ChatCompletionChunkResponseChoiceDelta as AzureChatCompletionChunkResponseChoiceDelta,
+		ContentFilterSeverityResult as AzureContentFilterSeverityResult,
+	};
+
+	use super::*;
+
+	fn fx_content_filter_results() -> AzureChatCompletionResponseContentFilterResults {
+		AzureChatCompletionResponseContentFilterResults {
+			sexual: None,
+			violence: None,
+			hate: Some(AzureContentFilterSeverityResult {
+				filtered: false,
+				severity: "safe".to_string(),
+			}),
+			self_harm: None,
+			profanity: None,
+			jailbreak: None,
+			error: None,
+		}
+	}
+
+	fn fx_prompt_filter_chunk() -> AzureChatCompletionChunkResponse {
+		AzureChatCompletionChunkResponse {
+			id: "".to_string(),
+			choices: vec![],
+			created: 0,
+			model: "".to_string(),
+			object: "".to_string(),
+			system_fingerprint: None,
+			prompt_filter_results: Some(vec![AzurePromptFilterResult {
+				prompt_index: 0,
+				content_filter_results: Some(fx_content_filter_results()),
+			}]),
+		}
+	}
+
+	fn fx_content_chunk() -> AzureChatCompletionChunkResponse {
+		AzureChatCompletionChunkResponse {
+			id: "chatcmpl-123".to_string(),
+			choices: vec![AzureChatCompletionChunkResponseChoice {
+				finish_reason: None,
+				index: 0,
+				delta: AzureChatCompletionChunkResponseChoiceDelta {
+					content: Some("Hello".to_string()),
+					role: None,
+					tool_calls: None,
+				},
+				content_filter_results: Some(fx_content_filter_results()),
+			}],
+			created: 1694268190,
+			model: "gpt-4o".to_string(),
+			object: "chat.completion.chunk".to_string(),
+			system_fingerprint: None,
+			prompt_filter_results: None,
+		}
+	}
+
+	#[test]
+	fn test_strip_annotation_only_chunk_transform_ok() -> Result<()> {
+		let data = fx_prompt_filter_chunk()
+			.to_openai_v1(TransformationContext { annotations: ContentFilterAnnotations::Strip });
+
+		assert!(data.response.is_none());
+
+		// Check if the prompt filter results were passed to the loss object.
+		assert_eq!(data.loss.prompt_filter_results.unwrap().len(), 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_normalize_annotation_only_chunk_transform_ok() -> Result<()> {
+		let data = fx_prompt_filter_chunk().to_openai_v1(TransformationContext {
+			annotations: ContentFilterAnnotations::Normalize,
+		});
+		let response = data.response.unwrap();
+
+		assert!(response.choices.is_empty());
+		assert_eq!(response.object, "chat.completion.chunk");
+
+		// Check if the prompt filter results were passed to the loss object.
+		assert_eq!(data.loss.prompt_filter_results.unwrap().len(), 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_content_chunk_transform_ok() -> Result<()> {
+		let data = fx_content_chunk()
+			.to_openai_v1(TransformationContext { annotations: ContentFilterAnnotations::Strip });
+		let response = data.response.unwrap();
+
+		assert_eq!(response.id, "chatcmpl-123");
+		assert_eq!(response.choices.len(), 1);
+		assert_eq!(response.choices[0].delta.content, Some("Hello".to_string()));
+
+		// Check if the content filter results were passed to the loss object.
+		assert_eq!(data.loss.content_filter_results, vec![(0, fx_content_filter_results())]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_strip_annotation_only_choice_transform_ok() -> Result<()> {
+		let mut fx_chunk = fx_content_chunk();
+		fx_chunk.choices.push(AzureChatCompletionChunkResponseChoice {
+			finish_reason: None,
+			index: 1,
+			delta: AzureChatCompletionChunkResponseChoiceDelta::default(),
+			content_filter_results: Some(fx_content_filter_results()),
+		});
+
+		let data = fx_chunk
+			.to_openai_v1(TransformationContext { annotations: ContentFilterAnnotations::Strip });
+		let response = data.response.unwrap();
+
+		assert_eq!(response.choices.len(), 1);
+		assert_eq!(response.choices[0].index, 0);
+		assert_eq!(data.loss.content_filter_results.len(), 2);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,80 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponse {
+	pub error: ErrorResponseError,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponseError {
+	/// The error code, such as `content_filter` or `DeploymentNotFound`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub code: Option<String>,
+
+	/// A human-readable error message.
+	pub message: String,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub param: Option<String>,
+
+	#[cfg_attr(feature = "serde", serde(rename = "type", skip_serializing_if = "Option::is_none"))]
+	pub r#type: Option<String>,
+
+	/// The HTTP status code of the error.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub status: Option<u16>,
+
+	/// Inner error with additional details. Content filter errors carry the
+	/// `ResponsibleAIPolicyViolation` code and the `content_filter_result` here.
+	#[cfg_attr(
+		feature = "serde",
+		serde(
+			rename = "innererror",
+			alias = "inner_error",
+			skip_serializing_if = "Option::is_none"
+		)
+	)]
+	pub inner_error: Option<serde_json::Value>,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_error_response_content_filter_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "error": {
+			"message": "The response was filtered due to the prompt triggering Azure OpenAI's content management policy.",
+			"type": null,
+			"param": "prompt",
+			"code": "content_filter",
+			"status": 400,
+			"innererror": {
+			  "code": "ResponsibleAIPolicyViolation",
+			  "content_filter_result": {
+				"hate": { "filtered": true, "severity": "high" }
+			  }
+			}
+		  }
+		})
+		.to_string();
+
+		let data: ErrorResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.error.code, Some("content_filter".to_string()));
+		assert_eq!(data.error.status, Some(400));
+		assert_eq!(data.error.inner_error.unwrap()["code"], json!("ResponsibleAIPolicyViolation"));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,109 @@
+use serde_json::json;
+
+use crate::openai::v1::error::response::{
+	ErrorResponse as OpenAIErrorResponse, ErrorResponseError as OpenAIErrorResponseError,
+};
+
+use crate::azure::v2024_02_01::error::response::ErrorResponse as AzureErrorResponse;
+
+impl AzureErrorResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		let error = &self.error;
+
+		// Azure usually leaves `type` empty, so fall back to the OpenAI type matching the status.
+		let r#type = error.r#type.clone().unwrap_or_else(|| match error.status {
+			Some(status) if status >= 500 => "server_error".to_string(),
+			_ => "invalid_request_error".to_string(),
+		});
+
+		Transformation {
+			response: OpenAIErrorResponse {
+				error: OpenAIErrorResponseError {
+					message: error.message.clone(),
+					r#type,
+					param: error.param.clone(),
+					code: error.code.clone(),
+					vendor: Some(json!({
+						"azure": {
+							"code": error.code,
+							"message": error.message,
+							"param": error.param,
+							"type": error.r#type,
+							"status": error.status,
+							"innererror": error.inner_error,
+						}
+					})),
+				},
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct Transformation {
+	pub response: OpenAIErrorResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use crate::azure::v2024_02_01::error::response::ErrorResponseError as AzureErrorResponseError;
+
+	use super::*;
+
+	#[test]
+	fn test_content_filter_error_transform_ok() -> Result<()> {
+		let fx_response = AzureErrorResponse {
+			error: AzureErrorResponseError {
+				code: Some("content_filter".to_string()),
+				message: "filtered".to_string(),
+				param: Some("prompt".to_string()),
+				r#type: None,
+				status: Some(400),
+				inner_error: Some(json!({"code": "ResponsibleAIPolicyViolation"})),
+			},
+		};
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.error.message, "filtered");
+		assert_eq!(data.response.error.r#type, "invalid_request_error");
+		assert_eq!(data.response.error.param, Some("prompt".to_string()));
+		assert_eq!(data.response.error.code, Some("content_filter".to_string()));
+
+		// Check if the original error was attached under the vendor field.
+		let vendor = data.response.error.vendor.unwrap();
+		assert_eq!(vendor["azure"]["innererror"]["code"], json!("ResponsibleAIPolicyViolation"));
+		assert_eq!(vendor["azure"]["status"], json!(400));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_server_error_transform_ok() -> Result<()> {
+		let fx_response = AzureErrorResponse {
+			error: AzureErrorResponseError {
+				code: Some("InternalServerError".to_string()),
+				message: "internal error".to_string(),
+				param: None,
+				r#type: None,
+				status: Some(500),
+				inner_error: None,
+			},
+		};
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.error.r#type, "server_error");
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,223 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatRequest {
+	/// The name of a compatible Cohere model or the ID of a fine-tuned model.
+	pub model: String,
+
+	/// A list of chat messages in chronological order, representing a conversation between the
+	/// user and the model.
+	pub messages: Vec<ChatMessage>,
+
+	/// A list of tools (functions) available to the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatTool>>,
+
+	/// A list of relevant documents that the model can cite to generate a more accurate reply.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub documents: Option<Vec<serde_json::Value>>,
+
+	/// Configuration for forcing the model output to adhere to the specified format. Either
+	/// `{"type": "text"}` or `{"type": "json_object", "json_schema": {...}}`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// Used to select the safety instruction inserted into the prompt. One of `CONTEXTUAL`,
+	/// `STRICT` or `OFF`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub safety_mode: Option<String>,
+
+	/// The maximum number of tokens the model will generate as part of the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// A list of up to 5 strings that the model will use to stop generation.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop_sequences: Option<Vec<String>>,
+
+	/// default: 0.3
+	/// A non-negative float that tunes the degree of randomness in generation.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// If specified, the backend will make a best effort to sample tokens deterministically.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	/// min value of 0.0, max value of 1.0
+	/// Used to reduce repetitiveness of generated tokens. The higher the value, the stronger a
+	/// penalty is applied to previously present tokens, proportional to how many times they have
+	/// already appeared in the prompt or prior generation.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// min value of 0.0, max value of 1.0
+	/// Used to reduce repetitiveness of generated tokens. Similar to frequency_penalty, except
+	/// that this penalty is applied equally to all tokens that have already appeared, regardless
+	/// of their exact frequencies.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// default: 0, min value of 0, max value of 500
+	/// Ensures that only the top k most likely tokens are considered for generation at each step.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub k: Option<u64>,
+
+	/// default: 0.75, min value of 0.01, max value of 0.99
+	/// Ensures that only the most likely tokens, with total probability mass of p, are
+	/// considered for generation at each step.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub p: Option<f64>,
+
+	/// Whether to return the log probabilities of the generated tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<bool>,
+
+	/// Forces the model to use a tool (`REQUIRED`) or to not use any (`NONE`). When not
+	/// specified, the model is free to decide.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatToolChoice>,
+
+	/// Whether to stream the response as a sequence of server-sent events.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatToolChoice {
+	#[cfg_attr(feature = "serde", serde(rename = "REQUIRED"))]
+	Required,
+	#[cfg_attr(feature = "serde", serde(rename = "NONE"))]
+	None,
+}
+
+// region:    --- ChatMessage
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "role"))]
+pub enum ChatMessage {
+	#[cfg_attr(feature = "serde", serde(rename = "system", alias = "system"))]
+	SystemMessage { content: ChatMessageContent },
+	#[cfg_attr(feature = "serde", serde(rename = "user", alias = "user"))]
+	UserMessage { content: ChatMessageContent },
+	#[cfg_attr(feature = "serde", serde(rename = "assistant", alias = "assistant"))]
+	AssistantMessage {
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		content: Option<ChatMessageContent>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		tool_calls: Option<Vec<AssistantToolCall>>,
+		/// A chain-of-thought style reflection and plan that the model generates when working
+		/// with tools.
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		tool_plan: Option<String>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "tool", alias = "tool"))]
+	ToolMessage { tool_call_id: String, content: ChatMessageContent },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum ChatMessageContent {
+	TextContent(String),
+	ArrayContentParts(Vec<ChatMessageContentPart>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatMessageContentPart {
+	#[cfg_attr(feature = "serde", serde(rename = "text", alias = "text"))]
+	TextContentPart { text: String },
+	#[cfg_attr(feature = "serde", serde(rename = "image_url", alias = "image_url"))]
+	ImageContentPart { image_url: ImageUrlContentPart },
+	#[cfg_attr(feature = "serde", serde(rename = "document", alias = "document"))]
+	DocumentContentPart { document: serde_json::Value },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageUrlContentPart {
+	pub url: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssistantToolCall {
+	pub id: String,
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: AssistantToolCallType,
+	pub function: AssistantToolCallFunction,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssistantToolCallType {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssistantToolCallFunction {
+	pub name: String,
+	pub arguments: String,
+}
+// endregion: --- ChatMessage
+
+// region:    --- Tools
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatTool {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionTool { function: ChatToolFunction },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatToolFunction {
+	pub name: String,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub description: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub parameters: Option<serde_json::Value>,
+}
+// endregion: --- Tools
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_chat_request_cohere_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "command-r-plus-08-2024",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": [{ "type": "text", "text": "What's the weather in Toronto?" }] },
+			{
+			  "role": "assistant",
+			  "tool_plan": "I will look up the weather in Toronto.",
+			  "tool_calls": [{ "id": "get_weather_1byjy32y4hvq", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\":\"Toronto\"}" } }]
+			},
+			{ "role": "tool", "tool_call_id": "get_weather_1byjy32y4hvq", "content": [{ "type": "document", "document": { "data": "{\"temperature\": 20}" } }] }
+		  ],
+		  "tool_choice": "REQUIRED",
+		  "k": 10,
+		  "p": 0.5
+		})
+		.to_string();
+
+		let data: ChatRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.messages.len(), 4);
+		assert_eq!(data.tool_choice, Some(ChatToolChoice::Required));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,237 @@
+// region:    --- Chat Response
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponse {
+	/// Unique identifier for the generated reply.
+	pub id: String,
+
+	/// The reason a chat request has finished. One of `COMPLETE`, `STOP_SEQUENCE`, `MAX_TOKENS`,
+	/// `TOOL_CALL` or `ERROR`.
+	pub finish_reason: String,
+
+	/// A message from the assistant role.
+	pub message: ChatResponseMessage,
+
+	/// Token counts of the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatResponseUsage>,
+
+	/// The log probabilities of the generated tokens, when requested.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponseMessage {
+	/// The role of the author of the message, which is always assistant.
+	pub role: String,
+	/// The text blocks generated by the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content: Option<Vec<ChatResponseContentBlock>>,
+	/// A chain-of-thought style reflection and plan that the model generates when working with
+	/// tools.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_plan: Option<String>,
+	/// The tool calls generated by the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<Vec<ChatResponseToolCall>>,
+	/// Citations of the documents used to generate the message.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub citations: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatResponseContentBlock {
+	#[cfg_attr(feature = "serde", serde(rename = "text", alias = "text"))]
+	TextBlock { text: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponseToolCall {
+	pub id: String,
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: String,
+	pub function: ChatResponseFunctionToolCall,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponseFunctionToolCall {
+	pub name: String,
+	pub arguments: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponseUsage {
+	/// The billed units of the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub billed_units: Option<ChatResponseUsageTokens>,
+	/// The tokens used by the model, including the ones of the prompt template.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tokens: Option<ChatResponseUsageTokens>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponseUsageTokens {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub input_tokens: Option<u64>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub output_tokens: Option<u64>,
+}
+// endregion: --- Chat Response
+
+// region:    --- Stream Events
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatStreamEvent {
+	/// The delta of this event uses empty arrays for content and tool calls, unlike the other
+	/// events, so it is kept as raw JSON.
+	#[cfg_attr(feature = "serde", serde(rename = "message-start", alias = "message-start"))]
+	MessageStart { id: String, delta: serde_json::Value },
+	#[cfg_attr(feature = "serde", serde(rename = "content-start", alias = "content-start"))]
+	ContentStart { index: u64, delta: ChatStreamEventDelta },
+	#[cfg_attr(feature = "serde", serde(rename = "content-delta", alias = "content-delta"))]
+	ContentDelta { index: u64, delta: ChatStreamEventDelta },
+	#[cfg_attr(feature = "serde", serde(rename = "content-end", alias = "content-end"))]
+	ContentEnd { index: u64 },
+	#[cfg_attr(feature = "serde", serde(rename = "tool-plan-delta", alias = "tool-plan-delta"))]
+	ToolPlanDelta { delta: ChatStreamEventDelta },
+	#[cfg_attr(feature = "serde", serde(rename = "tool-call-start", alias = "tool-call-start"))]
+	ToolCallStart { index: u64, delta: ChatStreamEventDelta },
+	#[cfg_attr(feature = "serde", serde(rename = "tool-call-delta", alias = "tool-call-delta"))]
+	ToolCallDelta { index: u64, delta: ChatStreamEventDelta },
+	#[cfg_attr(feature = "serde", serde(rename = "tool-call-end", alias = "tool-call-end"))]
+	ToolCallEnd { index: u64 },
+	#[cfg_attr(feature = "serde", serde(rename = "citation-start", alias = "citation-start"))]
+	CitationStart { index: u64, delta: serde_json::Value },
+	#[cfg_attr(feature = "serde", serde(rename = "citation-end", alias = "citation-end"))]
+	CitationEnd { index: u64 },
+	#[cfg_attr(feature = "serde", serde(rename = "message-end", alias = "message-end"))]
+	MessageEnd { delta: ChatStreamEventEndDelta },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatStreamEventDelta {
+	pub message: ChatStreamEventDeltaMessage,
+}
+
+/// Every event only sets the fields relevant to it, e.g. `content-delta` only carries the text.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatStreamEventDeltaMessage {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub role: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content: Option<ChatStreamEventDeltaContent>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_plan: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<ChatStreamEventDeltaToolCall>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatStreamEventDeltaContent {
+	#[cfg_attr(feature = "serde", serde(rename = "type", skip_serializing_if = "Option::is_none"))]
+	pub r#type: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub text: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatStreamEventDeltaToolCall {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub id: Option<String>,
+	#[cfg_attr(feature = "serde", serde(rename = "type", skip_serializing_if = "Option::is_none"))]
+	pub r#type: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub function: Option<ChatStreamEventDeltaFunctionToolCall>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatStreamEventDeltaFunctionToolCall {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub name: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub arguments: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatStreamEventEndDelta {
+	pub finish_reason: String,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatResponseUsage>,
+}
+// endregion: --- Stream Events
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_chat_response_cohere_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "c14c80c3-18eb-4519-9460-6c92edd8cfb4",
+		  "finish_reason": "COMPLETE",
+		  "message": {
+			"role": "assistant",
+			"content": [{ "type": "text", "text": "LLMs stand for Large Language Models." }]
+		  },
+		  "usage": {
+			"billed_units": { "input_tokens": 17, "output_tokens": 12 },
+			"tokens": { "input_tokens": 215, "output_tokens": 12 }
+		  }
+		})
+		.to_string();
+
+		let data: ChatResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(
+			data.message.content,
+			Some(vec![ChatResponseContentBlock::TextBlock {
+				text: "LLMs stand for Large Language Models.".to_string()
+			}])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_chat_stream_events_cohere_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_events = json!([
+		  { "type": "message-start", "id": "29f14a5a-11de-4cae-9800-25e4747408ea", "delta": { "message": { "role": "assistant", "content": [], "tool_plan": "", "tool_calls": [], "citations": [] } } },
+		  { "type": "content-start", "index": 0, "delta": { "message": { "content": { "type": "text", "text": "" } } } },
+		  { "type": "content-delta", "index": 0, "delta": { "message": { "content": { "text": "Hello" } } } },
+		  { "type": "content-end", "index": 0 },
+		  { "type": "tool-call-start", "index": 0, "delta": { "message": { "tool_calls": { "id": "get_weather_nsz5zm3w56q3", "type": "function", "function": { "name": "get_weather", "arguments": "" } } } } },
+		  { "type": "tool-call-delta", "index": 0, "delta": { "message": { "tool_calls": { "function": { "arguments": "{\"location\":\"Toronto\"}" } } } } },
+		  { "type": "tool-call-end", "index": 0 },
+		  { "type": "message-end", "delta": { "finish_reason": "TOOL_CALL", "usage": { "billed_units": { "input_tokens": 37, "output_tokens": 28 }, "tokens": { "input_tokens": 913, "output_tokens": 83 } } } }
+		])
+		.to_string();
+
+		let data: Vec<ChatStreamEvent> = serde_json::from_str(&fx_events)?;
+
+		assert_eq!(data.len(), 8);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	AssistantToolCallType as OpenAIAssistantToolCallType,
+	ChatCompletionMessage as OpenAIChatCompletionMessage,
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop, ChatCompletionTool as OpenAIChatCompletionTool,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+	UserMessageContent as OpenAIUserMessageContent,
+	UserMessageContentPart as OpenAIUserMessageContentPart,
+};
+
+use crate::cohere::v2::chat::request::{
+	AssistantToolCall as CohereAssistantToolCall,
+	AssistantToolCallFunction as CohereAssistantToolCallFunction,
+	AssistantToolCallType as CohereAssistantToolCallType, ChatMessage as CohereChatMessage,
+	ChatMessageContent as CohereChatMessageContent,
+	ChatMessageContentPart as CohereChatMessageContentPart, ChatRequest as CohereChatRequest,
+	ChatTool as CohereChatTool, ChatToolChoice as CohereChatToolChoice,
+	ChatToolFunction as CohereChatToolFunction, ImageUrlContentPart as CohereImageUrlContentPart,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_cohere_v2(&self, context: TransformationContext) -> Transformation {
+		// Cohere can only force or forbid tool calls, `auto` is the default behaviour and a named
+		// function can't be forced.
+		let (tool_choice, tool_choice_loss) = match self.tool_choice.clone() {
+			None => (None, None),
+			Some(OpenAIChatCompletionToolChoice::StringChoice(value)) => match value.as_str() {
+				"auto" => (None, None),
+				"none" => (Some(CohereChatToolChoice::None), None),
+				"required" => (Some(CohereChatToolChoice::Required), None),
+				_ => (None, Some(OpenAIChatCompletionToolChoice::StringChoice(value))),
+			},
+			Some(choice) => (None, Some(choice)),
+		};
+
+		Transformation {
+			request: CohereChatRequest {
+				model: self.model.clone(),
+				messages: self
+					.messages
+					.clone()
+					.into_iter()
+					.map(|message| match message {
+						OpenAIChatCompletionMessage::SystemMessage { content, .. } => CohereChatMessage::SystemMessage { content: CohereChatMessageContent::TextContent(content) },
+						OpenAIChatCompletionMessage::UserMessage { content, .. } => CohereChatMessage::UserMessage {
+							content: match content {
+								OpenAIUserMessageContent::TextContent(value) => CohereChatMessageContent::TextContent(value),
+								OpenAIUserMessageContent::ArrayContentParts(parts) => CohereChatMessageContent::ArrayContentParts(
+									parts
+										.into_iter()
+										.map(|part| match part {
+											OpenAIUserMessageContentPart::TextContentPart { text } => CohereChatMessageContentPart::TextContentPart { text },
+											OpenAIUserMessageContentPart::ImageContentPart { image_url } => CohereChatMessageContentPart::ImageContentPart { image_url: CohereImageUrlContentPart { url: image_url.url } },
+										})
+										.collect(),
+								),
+							},
+						},
+						OpenAIChatCompletionMessage::AssistantMessage { content, tool_calls, .. } => CohereChatMessage::AssistantMessage {
+							content: content.map(CohereChatMessageContent::TextContent),
+							tool_calls: tool_calls.map(|calls| {
+								calls
+									.into_iter()
+									.map(|call| CohereAssistantToolCall {
+										id: call.id,
+										r#type: match call.r#type {
+											OpenAIAssistantToolCallType::FunctionType => CohereAssistantToolCallType::FunctionType,
+										},
+										function: CohereAssistantToolCallFunction { name: call.function.name, arguments: call.function.arguments },
+									})
+									.collect()
+							}),
+							tool_plan: None,
+						},
+						OpenAIChatCompletionMessage::ToolMessage { content, tool_call_id } => CohereChatMessage::ToolMessage { tool_call_id, content: CohereChatMessageContent::TextContent(content) },
+					})
+					.collect(),
+				tools: self.tools.clone().map(|tls| {
+					tls.into_iter()
+						.map(|tool| match tool {
+							OpenAIChatCompletionTool::FunctionTool { function } => CohereChatTool::FunctionTool { function: CohereChatToolFunction { name: function.name, description: function.description, parameters: function.parameters } },
+						})
+						.collect()
+				}),
+				documents: context.documents,
+				response_format: self.response_format.clone().map(response_format),
+				safety_mode: context.safety_mode,
+				max_tokens: self.max_tokens,
+				stop_sequences: self.stop.clone().map(|stop| match stop {
+					OpenAIChatCompletionStop::StringStop(v) => vec![v],
+					OpenAIChatCompletionStop::ArrayStop(v) => v,
+				}),
+				temperature: self.temperature,
+				seed: self.seed,
+				frequency_penalty: self.frequency_penalty,
+				presence_penalty: self.presence_penalty,
+				k: context.k,
+				p: self.top_p,
+				logprobs: self.logprobs,
+				tool_choice,
+				stream: self.stream,
+			},
+			loss: TransformationLoss {
+				n: self.n,
+				top_logprobs: self.top_logprobs,
+				logit_bias: self.logit_bias.clone(),
+				user: self.user.clone(),
+				tool_choice: tool_choice_loss,
+			},
+		}
+	}
+}
+
+/// OpenAI nests the schema under `json_schema.schema` with the `json_schema` type, while Cohere
+/// takes it directly under `json_schema` with the `json_object` type.
+fn response_format(format: serde_json::Value) -> serde_json::Value {
+	if format.get("type").and_then(|value| value.as_str()) != Some("json_schema") {
+		return format;
+	}
+
+	match format.get("json_schema").and_then(|value| value.get("schema")) {
+		Some(schema) => serde_json::json!({ "type": "json_object", "json_schema": schema }),
+		None => serde_json::json!({ "type": "json_object" }),
+	}
+}
+
+pub struct TransformationLoss {
+	pub n: Option<u64>,
+	pub top_logprobs: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub user: Option<String>,
+	/// Set when the tool choice has no Cohere equivalent.
+	pub tool_choice: Option<OpenAIChatCompletionToolChoice>,
+}
+
+pub struct TransformationContext {
+	pub documents: Option<Vec<serde_json::Value>>,
+	pub safety_mode: Option<String>,
+	pub k: Option<u64>,
+}
+
+pub struct Transformation {
+	pub request: CohereChatRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	fn fx_context() -> TransformationContext {
+		TransformationContext { documents: None, safety_mode: None, k: None }
+	}
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "command-r-plus-08-2024",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "Hello!" }
+		  ],
+		  "n": 2,
+		  "top_p": 0.5,
+		  "stop": "END",
+		  "user": "user-1234",
+		  "response_format": {
+			"type": "json_schema",
+			"json_schema": { "name": "greeting", "schema": { "type": "object" } }
+		  }
+		}))?;
+
+		let data = fx_request.to_cohere_v2(fx_context());
+
+		assert_eq!(data.request.p, Some(0.5));
+		assert_eq!(data.request.stop_sequences, Some(vec!["END".to_string()]));
+		assert_eq!(
+			data.request.response_format,
+			Some(json!({ "type": "json_object", "json_schema": { "type": "object" } }))
+		);
+
+		// Check if the parameters Cohere doesn't support were passed to the loss object.
+		assert_eq!(data.loss.n, Some(2));
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_choice_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "command-r-plus-08-2024",
+		  "messages": [{ "role": "user", "content": "What's the weather in Toronto?" }],
+		  "tools": [{ "type": "function", "function": { "name": "get_weather" } }],
+		  "tool_choice": { "type": "function", "function": { "name": "get_weather" } }
+		}))?;
+
+		let data = fx_request.to_cohere_v2(fx_context());
+
+		assert_eq!(data.request.tool_choice, None);
+
+		// Check if the named tool choice was passed to the loss object.
+		assert_eq!(data.loss.tool_choice, fx_request.tool_choice);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,337 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as OpenAIChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceDelta as OpenAIChatCompletionChunkResponseChoiceDelta,
+	ChatCompletionChunkResponseChoiceFunctionToolCall as OpenAIChatCompletionChunkResponseChoiceFunctionToolCall,
+	ChatCompletionChunkResponseChoiceToolCall as OpenAIChatCompletionChunkResponseChoiceToolCall,
+	ChatCompletionChunkResponseChoiceToolCallType as OpenAIChatCompletionChunkResponseChoiceToolCallType,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionObjectResponseChoice as OpenAIChatCompletionObjectResponseChoice,
+	ChatCompletionObjectResponseChoiceMessage as OpenAIChatCompletionObjectResponseChoiceMessage,
+	ChatCompletionObjectResponseChoiceToolCall as OpenAIChatCompletionObjectResponseChoiceToolCall,
+	ChatCompletionResponseChoiceFunctionToolCall as OpenAIChatCompletionResponseChoiceFunctionToolCall,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+};
+
+use crate::cohere::v2::chat::response::{
+	ChatResponse as CohereChatResponse, ChatResponseContentBlock as CohereChatResponseContentBlock,
+	ChatResponseUsage as CohereChatResponseUsage, ChatStreamEvent as CohereChatStreamEvent,
+	ChatStreamEventDelta as CohereChatStreamEventDelta,
+};
+
+// region:    --- Object Response
+impl CohereChatResponse {
+	pub fn to_openai_v1(&self, context: TransformationContext) -> Transformation {
+		let text: Vec<String> = self
+			.message
+			.content
+			.clone()
+			.unwrap_or_default()
+			.into_iter()
+			.map(|block| match block {
+				CohereChatResponseContentBlock::TextBlock { text } => text,
+			})
+			.collect();
+
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: vec![OpenAIChatCompletionObjectResponseChoice {
+					finish_reason: finish_reason(&self.finish_reason),
+					index: 0,
+					message: OpenAIChatCompletionObjectResponseChoiceMessage {
+						content: if text.is_empty() { None } else { Some(text.concat()) },
+						role: "assistant".to_string(),
+						tool_calls: self.message.tool_calls.clone().filter(|calls| !calls.is_empty()).map(|calls| {
+							calls
+								.into_iter()
+								.map(|call| OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+									id: call.id,
+									function: OpenAIChatCompletionResponseChoiceFunctionToolCall { name: call.function.name, arguments: call.function.arguments },
+								})
+								.collect()
+						}),
+						annotations: None,
+					},
+					logprobs: None,
+				}],
+				created: context.created,
+				model: context.model,
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: usage(self.usage.as_ref()),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				tool_plan: self.message.tool_plan.clone(),
+				citations: self.message.citations.clone(),
+				logprobs: self.logprobs.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub tool_plan: Option<String>,
+	pub citations: Option<Vec<serde_json::Value>>,
+	/// Cohere's log probabilities are per token id, which doesn't fit OpenAI's format.
+	pub logprobs: Option<Vec<serde_json::Value>>,
+}
+
+/// Cohere doesn't return the model or a creation timestamp, so they have to be provided by the
+/// caller.
+pub struct TransformationContext {
+	pub model: String,
+	pub created: u64,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Events
+impl CohereChatStreamEvent {
+	/// The id of the stream is only sent with the first event, so the context has to be shared by
+	/// every event of the same stream. Returns `None` for events that have no OpenAI counterpart
+	/// (tool plans, citations and the end of content or tool call blocks).
+	pub fn to_openai_v1(
+		&self,
+		context: &mut StreamTransformationContext,
+	) -> Option<OpenAIChatCompletionChunkResponse> {
+		match self {
+			CohereChatStreamEvent::MessageStart { id, .. } => {
+				context.id = id.clone();
+
+				Some(context.chunk(OpenAIChatCompletionChunkResponseChoiceDelta {
+					content: Some("".to_string()),
+					role: Some("assistant".to_string()),
+					tool_calls: None,
+					annotations: None,
+				}))
+			},
+			CohereChatStreamEvent::ContentStart { delta, .. }
+			| CohereChatStreamEvent::ContentDelta { delta, .. } => {
+				let text = delta
+					.message
+					.content
+					.clone()
+					.and_then(|content| content.text)
+					.filter(|text| !text.is_empty())?;
+
+				Some(context.chunk(OpenAIChatCompletionChunkResponseChoiceDelta {
+					content: Some(text),
+					..Default::default()
+				}))
+			},
+			CohereChatStreamEvent::ToolCallStart { index, delta }
+			| CohereChatStreamEvent::ToolCallDelta { index, delta } => {
+				let tool_call = tool_call(*index, delta)?;
+
+				Some(context.chunk(OpenAIChatCompletionChunkResponseChoiceDelta {
+					tool_calls: Some(vec![tool_call]),
+					..Default::default()
+				}))
+			},
+			CohereChatStreamEvent::MessageEnd { delta } => {
+				let mut chunk =
+					context.chunk(OpenAIChatCompletionChunkResponseChoiceDelta::default());
+				chunk.choices[0].finish_reason = Some(finish_reason(&delta.finish_reason));
+				// Cohere has no separate usage event, so it is sent with the finish reason.
+				if context.include_usage {
+					chunk.usage = Some(usage(delta.usage.as_ref()));
+				}
+				Some(chunk)
+			},
+			CohereChatStreamEvent::ContentEnd { .. }
+			| CohereChatStreamEvent::ToolPlanDelta { .. }
+			| CohereChatStreamEvent::ToolCallEnd { .. }
+			| CohereChatStreamEvent::CitationStart { .. }
+			| CohereChatStreamEvent::CitationEnd { .. } => None,
+		}
+	}
+}
+
+pub struct StreamTransformationContext {
+	pub model: String,
+	pub created: u64,
+	/// Add the usage to the last chunk, like OpenAI does with `stream_options.include_usage`.
+	pub include_usage: bool,
+	id: String,
+}
+
+impl StreamTransformationContext {
+	pub fn new(model: String, created: u64, include_usage: bool) -> Self {
+		StreamTransformationContext { model, created, include_usage, id: "".to_string() }
+	}
+
+	fn chunk(
+		&self,
+		delta: OpenAIChatCompletionChunkResponseChoiceDelta,
+	) -> OpenAIChatCompletionChunkResponse {
+		OpenAIChatCompletionChunkResponse {
+			id: self.id.clone(),
+			choices: vec![OpenAIChatCompletionChunkResponseChoice {
+				finish_reason: None,
+				index: 0,
+				delta,
+				logprobs: None,
+			}],
+			created: self.created,
+			model: self.model.clone(),
+			system_fingerprint: None,
+			object: "chat.completion.chunk".to_string(),
+			usage: None,
+			service_tier: None,
+		}
+	}
+}
+
+/// Cohere numbers tool calls separately from the content blocks, so the event index is the
+/// OpenAI tool call index.
+fn tool_call(
+	index: u64,
+	delta: &CohereChatStreamEventDelta,
+) -> Option<OpenAIChatCompletionChunkResponseChoiceToolCall> {
+	let call = delta.message.tool_calls.clone()?;
+
+	Some(OpenAIChatCompletionChunkResponseChoiceToolCall {
+		index,
+		r#type: call
+			.id
+			.as_ref()
+			.map(|_| OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType),
+		id: call.id,
+		function: call.function.map(|function| {
+			OpenAIChatCompletionChunkResponseChoiceFunctionToolCall {
+				name: function.name,
+				arguments: function.arguments,
+			}
+		}),
+	})
+}
+// endregion: --- Stream Events
+
+fn finish_reason(finish_reason: &str) -> String {
+	match finish_reason {
+		"MAX_TOKENS" => "length",
+		"TOOL_CALL" => "tool_calls",
+		// COMPLETE, STOP_SEQUENCE and ERROR
+		_ => "stop",
+	}
+	.to_string()
+}
+
+/// Uses the billed units, like the embed transformer, falling back to the raw token counts.
+fn usage(usage: Option<&CohereChatResponseUsage>) -> OpenAIChatCompletionResponseUsage {
+	let tokens = usage.and_then(|usage| usage.billed_units.as_ref().or(usage.tokens.as_ref()));
+	let prompt_tokens = tokens.and_then(|tokens| tokens.input_tokens).unwrap_or(0);
+	let completion_tokens = tokens.and_then(|tokens| tokens.output_tokens).unwrap_or(0);
+
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens,
+		prompt_tokens,
+		total_tokens: prompt_tokens + completion_tokens,
+		prompt_tokens_details: None,
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: CohereChatResponse = serde_json::from_value(json!({
+		  "id": "c14c80c3-18eb-4519-9460-6c92edd8cfb4",
+		  "finish_reason": "TOOL_CALL",
+		  "message": {
+			"role": "assistant",
+			"tool_plan": "I will look up the weather in Toronto.",
+			"tool_calls": [{ "id": "get_weather_1byjy32y4hvq", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\":\"Toronto\"}" } }]
+		  },
+		  "usage": {
+			"billed_units": { "input_tokens": 37, "output_tokens": 28 },
+			"tokens": { "input_tokens": 913, "output_tokens": 83 }
+		  }
+		}))?;
+
+		let data = fx_response.to_openai_v1(TransformationContext {
+			model: "command-r-plus-08-2024".to_string(),
+			created: 1700000000,
+		});
+
+		assert_eq!(data.response.model, "command-r-plus-08-2024");
+		assert_eq!(data.response.choices[0].finish_reason, "tool_calls");
+		assert_eq!(data.response.choices[0].message.content, None);
+		assert_eq!(
+			data.response.choices[0].message.tool_calls.as_ref().map(|calls| calls.len()),
+			Some(1)
+		);
+		assert_eq!(
+			data.response.usage,
+			OpenAIChatCompletionResponseUsage {
+				completion_tokens: 28,
+				prompt_tokens: 37,
+				total_tokens: 65,
+				prompt_tokens_details: None,
+			}
+		);
+
+		// Check if the tool plan was passed to the loss object.
+		assert_eq!(data.loss.tool_plan, Some("I will look up the weather in Toronto.".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stream_events_transform_ok() -> Result<()> {
+		let fx_events: Vec<CohereChatStreamEvent> = serde_json::from_value(json!([
+		  { "type": "message-start", "id": "29f14a5a-11de-4cae-9800-25e4747408ea", "delta": { "message": { "role": "assistant", "content": [], "tool_plan": "", "tool_calls": [], "citations": [] } } },
+		  { "type": "tool-plan-delta", "delta": { "message": { "tool_plan": "I will look up the weather." } } },
+		  { "type": "tool-call-start", "index": 0, "delta": { "message": { "tool_calls": { "id": "get_weather_nsz5zm3w56q3", "type": "function", "function": { "name": "get_weather", "arguments": "" } } } } },
+		  { "type": "tool-call-delta", "index": 0, "delta": { "message": { "tool_calls": { "function": { "arguments": "{\"location\":\"Toronto\"}" } } } } },
+		  { "type": "tool-call-end", "index": 0 },
+		  { "type": "message-end", "delta": { "finish_reason": "TOOL_CALL", "usage": { "billed_units": { "input_tokens": 37, "output_tokens": 28 } } } }
+		]))?;
+
+		let mut context = StreamTransformationContext::new(
+			"command-r-plus-08-2024".to_string(),
+			1700000000,
+			true,
+		);
+		let data: Vec<OpenAIChatCompletionChunkResponse> =
+			fx_events.iter().filter_map(|event| event.to_openai_v1(&mut context)).collect();
+
+		assert_eq!(data.len(), 4);
+		assert!(data.iter().all(|chunk| chunk.id == "29f14a5a-11de-4cae-9800-25e4747408ea"));
+
+		let tool_call = data[1].choices[0].delta.tool_calls.clone().unwrap().remove(0);
+		assert_eq!(tool_call.id, Some("get_weather_nsz5zm3w56q3".to_string()));
+		assert_eq!(
+			tool_call.r#type,
+			Some(OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType)
+		);
+
+		let tool_call = data[2].choices[0].delta.tool_calls.clone().unwrap().remove(0);
+		assert_eq!(tool_call.id, None);
+		assert_eq!(
+			tool_call.function.unwrap().arguments,
+			Some("{\"location\":\"Toronto\"}".to_string())
+		);
+
+		assert_eq!(data[3].choices[0].finish_reason, Some("tool_calls".to_string()));
+		assert_eq!(data[3].usage.as_ref().map(|usage| usage.total_tokens), Some(65));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
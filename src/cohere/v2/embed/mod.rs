@@ -0,0 +1,4 @@
+pub mod request;
+pub mod response;
+
+#[cfg(feature = "transformer")] pub mod transformer;
@@ -0,0 +1,125 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedRequest {
+	/// The identifier of the model. Smaller "light" models are faster, while larger models will
+	/// perform better.
+	pub model: String,
+
+	/// Specifies the type of input passed to the model. Required for embedding models v3 and
+	/// higher.
+	pub input_type: EmbedInputType,
+
+	/// An array of strings for the model to embed. Maximum number of texts per call is 96.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub texts: Option<Vec<String>>,
+
+	/// An array of image data URIs for the model to embed. Maximum number of images per call is 1.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub images: Option<Vec<String>>,
+
+	/// Specifies the types of embeddings you want to get back. Can be one or more of `float`,
+	/// `int8`, `uint8`, `binary` or `ubinary`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub embedding_types: Option<Vec<EmbedEmbeddingType>>,
+
+	/// The number of dimensions of the output embedding. This is only available for
+	/// `embed-v4` and newer models. Possible values are 256, 512, 1024, and 1536.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub output_dimension: Option<i64>,
+
+	/// One of `NONE|START|END` to specify how the API will handle inputs longer than the maximum
+	/// token length. Passing `START` will discard the start of the input. `END` will discard the
+	/// end of the input.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub truncate: Option<EmbedTruncate>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmbedInputType {
+	#[cfg_attr(feature = "serde", serde(rename = "search_document"))]
+	SearchDocument,
+	#[cfg_attr(feature = "serde", serde(rename = "search_query"))]
+	SearchQuery,
+	#[cfg_attr(feature = "serde", serde(rename = "classification"))]
+	Classification,
+	#[cfg_attr(feature = "serde", serde(rename = "clustering"))]
+	Clustering,
+	#[cfg_attr(feature = "serde", serde(rename = "image"))]
+	Image,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmbedEmbeddingType {
+	#[cfg_attr(feature = "serde", serde(rename = "float"))]
+	Float,
+	#[cfg_attr(feature = "serde", serde(rename = "int8"))]
+	Int8,
+	#[cfg_attr(feature = "serde", serde(rename = "uint8"))]
+	Uint8,
+	#[cfg_attr(feature = "serde", serde(rename = "binary"))]
+	Binary,
+	#[cfg_attr(feature = "serde", serde(rename = "ubinary"))]
+	Ubinary,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmbedTruncate {
+	#[cfg_attr(feature = "serde", serde(rename = "NONE"))]
+	None,
+	#[cfg_attr(feature = "serde", serde(rename = "START"))]
+	Start,
+	#[cfg_attr(feature = "serde", serde(rename = "END"))]
+	End,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embed_cohere_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "embed-english-v3.0",
+		  "texts": ["hello", "goodbye"],
+		  "input_type": "classification",
+		  "embedding_types": ["float"]
+		})
+		.to_string();
+
+		let data: EmbedRequest = serde_json::from_str(&fx_request).unwrap();
+
+		assert_eq!(data.input_type, EmbedInputType::Classification);
+		assert_eq!(data.embedding_types, Some(vec![EmbedEmbeddingType::Float]));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_embed_input_type_01_decode_fail() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "embed-english-v3.0",
+		  "texts": ["hello"],
+		  "input_type": "invalid"
+		})
+		.to_string();
+
+		let data: serde_json::error::Result<EmbedRequest> = serde_json::from_str(&fx_request);
+
+		assert!(data.is_err());
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,109 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedResponse {
+	/// A unique identifier for the embed request.
+	pub id: String,
+
+	/// An object with different embedding types. The length of each embedding type array will be
+	/// the same as the length of the original texts array.
+	pub embeddings: EmbedResponseEmbeddings,
+
+	/// The text entries for which embeddings were returned.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub texts: Option<Vec<String>>,
+
+	/// Metadata about the request, including the billed units.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub meta: Option<EmbedResponseMeta>,
+
+	/// The response type, which is always "embeddings_by_type" for the v2 API.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_type: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedResponseEmbeddings {
+	/// An array of float embeddings.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub float: Option<Vec<Vec<f64>>>,
+	/// An array of signed int8 embeddings. Each value is between -128 and 127.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub int8: Option<Vec<Vec<i64>>>,
+	/// An array of unsigned int8 embeddings. Each value is between 0 and 255.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub uint8: Option<Vec<Vec<i64>>>,
+	/// An array of packed signed binary embeddings.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub binary: Option<Vec<Vec<i64>>>,
+	/// An array of packed unsigned binary embeddings.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub ubinary: Option<Vec<Vec<i64>>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedResponseMeta {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub api_version: Option<serde_json::Value>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub billed_units: Option<EmbedResponseBilledUnits>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedResponseBilledUnits {
+	/// The number of billed input tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub input_tokens: Option<u64>,
+	/// The number of billed output tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub output_tokens: Option<u64>,
+	/// The number of billed images.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub images: Option<u64>,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embed_response_cohere_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "da6e531f-54c6-4a73-bf92-f60566d8d753",
+		  "embeddings": {
+			"float": [[0.016296387, -0.008354187, -0.04699707]]
+		  },
+		  "texts": ["hello"],
+		  "meta": {
+			"api_version": {
+			  "version": "2"
+			},
+			"billed_units": {
+			  "input_tokens": 1
+			}
+		  },
+		  "response_type": "embeddings_by_type"
+		})
+		.to_string();
+
+		let data: EmbedResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.embeddings.float, Some(vec![vec![0.016296387, -0.008354187, -0.04699707]]));
+		assert_eq!(data.embeddings.int8, None);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,161 @@
+use crate::openai::v1::embeddings::request::{
+	EmbeddingsRequest as OpenAIEmbeddingsRequest,
+	EmbeddingsRequestInput as OpenAIEmbeddingsRequestInput,
+};
+
+use crate::cohere::v2::embed::request::{
+	EmbedEmbeddingType as CohereEmbedEmbeddingType, EmbedInputType as CohereEmbedInputType,
+	EmbedRequest as CohereEmbedRequest, EmbedTruncate as CohereEmbedTruncate,
+};
+
+impl OpenAIEmbeddingsRequest {
+	pub fn to_cohere_v2(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		let texts = match self.input.clone() {
+			OpenAIEmbeddingsRequestInput::String(value) => vec![value],
+			OpenAIEmbeddingsRequestInput::ArrayString(values) => values,
+			OpenAIEmbeddingsRequestInput::ArrayInt(_)
+			| OpenAIEmbeddingsRequestInput::ArrayArrayInt(_) =>
+				return Err(TransformationError::TokenInputNotSupported),
+		};
+
+		// Cohere has no base64 output, so anything other than float is requested as float and
+		// reported as lost.
+		let encoding_format = match self.encoding_format.as_deref() {
+			None | Some("float") => None,
+			Some(_) => self.encoding_format.clone(),
+		};
+
+		Ok(Transformation {
+			request: CohereEmbedRequest {
+				model: self.model.clone(),
+				input_type: context.input_type,
+				texts: Some(texts),
+				images: None,
+				embedding_types: Some(vec![CohereEmbedEmbeddingType::Float]),
+				output_dimension: self.dimensions,
+				truncate: context.truncate,
+			},
+			loss: TransformationLoss { encoding_format, user: self.user.clone() },
+		})
+	}
+}
+
+pub struct TransformationLoss {
+	pub encoding_format: Option<String>,
+	pub user: Option<String>,
+}
+
+pub struct TransformationContext {
+	pub input_type: CohereEmbedInputType,
+	pub truncate: Option<CohereEmbedTruncate>,
+}
+
+pub struct Transformation {
+	pub request: CohereEmbedRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// Cohere only embeds text, so token array inputs can't be forwarded.
+	TokenInputNotSupported,
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::TokenInputNotSupported =>
+				write!(f, "token array inputs are not supported by Cohere"),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayString(vec![
+				"hello".to_string(),
+				"world".to_string(),
+			]),
+			model: "embed-v4.0".to_string(),
+			encoding_format: Some("float".to_string()),
+			dimensions: Some(256),
+			user: Some("user-1234".to_string()),
+		};
+
+		let data = fx_request.to_cohere_v2(TransformationContext {
+			input_type: CohereEmbedInputType::SearchQuery,
+			truncate: None,
+		})?;
+
+		assert_eq!(data.request.model, fx_request.model);
+		assert_eq!(data.request.input_type, CohereEmbedInputType::SearchQuery);
+		assert_eq!(data.request.texts, Some(vec!["hello".to_string(), "world".to_string()]));
+		assert_eq!(data.request.embedding_types, Some(vec![CohereEmbedEmbeddingType::Float]));
+		assert_eq!(data.request.output_dimension, Some(256));
+
+		// Check if the parameters Cohere can't honor were passed to the loss object.
+		assert_eq!(data.loss.encoding_format, None);
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_base64_request_transform_ok() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::String("hello".to_string()),
+			model: "embed-v4.0".to_string(),
+			encoding_format: Some("base64".to_string()),
+			dimensions: None,
+			user: None,
+		};
+
+		let data = fx_request.to_cohere_v2(TransformationContext {
+			input_type: CohereEmbedInputType::SearchDocument,
+			truncate: Some(CohereEmbedTruncate::End),
+		})?;
+
+		assert_eq!(data.request.texts, Some(vec!["hello".to_string()]));
+		assert_eq!(data.request.embedding_types, Some(vec![CohereEmbedEmbeddingType::Float]));
+		assert_eq!(data.request.truncate, Some(CohereEmbedTruncate::End));
+		assert_eq!(data.loss.encoding_format, Some("base64".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_token_input_request_transform_fail() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayInt(vec![1, 2, 3]),
+			model: "embed-v4.0".to_string(),
+			encoding_format: None,
+			dimensions: None,
+			user: None,
+		};
+
+		let data = fx_request.to_cohere_v2(TransformationContext {
+			input_type: CohereEmbedInputType::SearchDocument,
+			truncate: None,
+		});
+
+		assert_eq!(data.err(), Some(TransformationError::TokenInputNotSupported));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,115 @@
+use crate::openai::v1::embeddings::response::{
+	EmbeddingsResponse as OpenAIEmbeddingsResponse,
+	EmbeddingsResponseData as OpenAIEmbeddingsResponseData,
+	EmbeddingsResponseEmbedding as OpenAIEmbeddingsResponseEmbedding,
+	EmbeddingsResponseUsage as OpenAIEmbeddingsResponseUsage,
+};
+
+use crate::cohere::v2::embed::response::EmbedResponse as CohereEmbedResponse;
+
+impl CohereEmbedResponse {
+	pub fn to_openai_v1(&self, context: TransformationContext) -> Transformation {
+		let input_tokens = self
+			.meta
+			.as_ref()
+			.and_then(|meta| meta.billed_units.as_ref())
+			.and_then(|units| units.input_tokens)
+			.unwrap_or(0);
+
+		Transformation {
+			response: OpenAIEmbeddingsResponse {
+				object: "list".to_string(),
+				data: self
+					.embeddings
+					.float
+					.clone()
+					.unwrap_or_default()
+					.into_iter()
+					.enumerate()
+					.map(|(index, embedding)| OpenAIEmbeddingsResponseData {
+						object: "embedding".to_string(),
+						embedding: OpenAIEmbeddingsResponseEmbedding::Float(embedding),
+						index: index as u64,
+					})
+					.collect(),
+				model: context.model,
+				usage: OpenAIEmbeddingsResponseUsage {
+					prompt_tokens: input_tokens,
+					total_tokens: input_tokens,
+				},
+			},
+			loss: TransformationLoss { id: self.id.clone() },
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub id: String,
+}
+
+pub struct TransformationContext {
+	pub model: String,
+}
+
+pub struct Transformation {
+	pub response: OpenAIEmbeddingsResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use crate::cohere::v2::embed::response::{
+		EmbedResponseBilledUnits, EmbedResponseEmbeddings, EmbedResponseMeta,
+	};
+
+	use super::*;
+
+	#[test]
+	fn test_basic_response_transform_ok() -> Result<()> {
+		let fx_response = CohereEmbedResponse {
+			id: "my-id".to_string(),
+			embeddings: EmbedResponseEmbeddings {
+				float: Some(vec![vec![0.1, 0.2], vec![0.3, 0.4]]),
+				int8: None,
+				uint8: None,
+				binary: None,
+				ubinary: None,
+			},
+			texts: None,
+			meta: Some(EmbedResponseMeta {
+				api_version: None,
+				billed_units: Some(EmbedResponseBilledUnits {
+					input_tokens: Some(4),
+					output_tokens: None,
+					images: None,
+				}),
+				warnings: None,
+			}),
+			response_type: Some("embeddings_by_type".to_string()),
+		};
+
+		let data =
+			fx_response.to_openai_v1(TransformationContext { model: "embed-v4.0".to_string() });
+
+		// Check if the id was passed to the loss object.
+		assert_eq!(data.loss.id, "my-id");
+
+		assert_eq!(data.response.model, "embed-v4.0");
+		assert_eq!(data.response.data.len(), 2);
+		assert_eq!(data.response.data[1].index, 1);
+		assert_eq!(
+			data.response.data[1].embedding,
+			OpenAIEmbeddingsResponseEmbedding::Float(vec![0.3, 0.4])
+		);
+		assert_eq!(data.response.usage.prompt_tokens, 4);
+		assert_eq!(data.response.usage.total_tokens, 4);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
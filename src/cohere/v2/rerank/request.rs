@@ -0,0 +1,65 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RerankRequest {
+	/// The identifier of the model to use, eg `rerank-v3.5`.
+	pub model: String,
+
+	/// The search query.
+	pub query: String,
+
+	/// A list of texts that will be compared to the query. For optimal performance we recommend
+	/// against sending more than 1,000 documents in a single request.
+	pub documents: Vec<String>,
+
+	/// Limits the number of returned rerank results to the specified value. If not passed, all
+	/// the rerank results will be returned.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_n: Option<u64>,
+
+	/// default: 4096
+	/// Defaults to 4096. Long documents will be automatically truncated to the specified number
+	/// of tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens_per_doc: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_rerank_request_01_encode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = RerankRequest {
+			model: "rerank-v3.5".to_string(),
+			query: "What is the capital of the United States?".to_string(),
+			documents: vec![
+				"Carson City is the capital city of the American state of Nevada.".to_string(),
+				"Washington, D.C. is the capital of the United States.".to_string(),
+			],
+			top_n: Some(1),
+			max_tokens_per_doc: None,
+		};
+
+		let data = serde_json::to_value(&fx_request)?;
+
+		assert_eq!(
+			data,
+			json!({
+			  "model": "rerank-v3.5",
+			  "query": "What is the capital of the United States?",
+			  "documents": ["Carson City is the capital city of the American state of Nevada.", "Washington, D.C. is the capital of the United States."],
+			  "top_n": 1
+			})
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,80 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RerankResponse {
+	/// A unique identifier for the rerank request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub id: Option<String>,
+
+	/// An ordered list of ranked documents, from most to least relevant.
+	pub results: Vec<RerankResponseResult>,
+
+	/// Metadata about the request, including the billed units.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub meta: Option<RerankResponseMeta>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RerankResponseResult {
+	/// Corresponds to the index in the original list of documents to which the ranked document
+	/// belongs.
+	pub index: u64,
+	/// Relevance scores are normalized to be in the range [0, 1]. Scores close to 1 indicate a
+	/// high relevance to the query, and scores closer to 0 indicate low relevance.
+	pub relevance_score: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RerankResponseMeta {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub api_version: Option<serde_json::Value>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub billed_units: Option<RerankResponseBilledUnits>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RerankResponseBilledUnits {
+	/// The number of billed search units.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_units: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_rerank_response_cohere_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "results": [
+			{ "index": 3, "relevance_score": 0.999071 },
+			{ "index": 4, "relevance_score": 0.7867867 }
+		  ],
+		  "id": "07734bd2-2473-4f07-94e1-0d9f0e6843cf",
+		  "meta": {
+			"api_version": { "version": "2" },
+			"billed_units": { "search_units": 1 }
+		  }
+		})
+		.to_string();
+
+		let data: RerankResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.results[0], RerankResponseResult { index: 3, relevance_score: 0.999071 });
+		assert_eq!(data.meta.unwrap().billed_units.unwrap().search_units, Some(1));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
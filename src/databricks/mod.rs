@@ -0,0 +1 @@
+pub mod serving_endpoints;
@@ -0,0 +1,3 @@
+pub mod request;
+
+#[cfg(feature = "transformer")] pub mod transformer;
@@ -0,0 +1,109 @@
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionStop, ChatCompletionTool, ChatCompletionToolChoice,
+};
+
+/// The chat request of Databricks serving endpoints. The model is chosen by the endpoint the
+/// request is sent to, see [`invocations_path`], so it has no model field. Responses have the
+/// OpenAI shape.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// A list of messages representing the current conversation.
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// The maximum number of tokens to generate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// default: false
+	/// Stream responses back to a client in order to allow partial results for requests.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// default: 1.0
+	/// A float in [0,2] representing the sampling temperature.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// default: 1.0
+	/// The probability threshold used for nucleus sampling.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// Defines the number of k most likely tokens to use for top-k-filtering.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// Model stops generating further tokens when any one of the sequences in stop is
+	/// encountered.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<ChatCompletionStop>,
+
+	/// default: 1
+	/// The number of responses to generate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub n: Option<u64>,
+
+	/// A list of tools the model may call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	/// Controls which (if any) tool is called by the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+
+	/// An object specifying the format that the model must output.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// default: false
+	/// Whether to return the log probabilities of the sampled tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<bool>,
+
+	/// The number of most likely tokens to return the log probabilities of at each step.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_logprobs: Option<i64>,
+}
+
+/// The path, relative to the workspace URL, that queries the given serving endpoint.
+pub fn invocations_path(endpoint_name: &str) -> String {
+	format!("/serving-endpoints/{endpoint_name}/invocations")
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_request_databricks_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "What is a mixture of experts model?" }
+		  ],
+		  "max_tokens": 128,
+		  "top_k": 40
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.messages.len(), 2);
+		assert_eq!(data.top_k, Some(40));
+		assert_eq!(
+			invocations_path("databricks-meta-llama-3-3-70b-instruct"),
+			"/serving-endpoints/databricks-meta-llama-3-3-70b-instruct/invocations"
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
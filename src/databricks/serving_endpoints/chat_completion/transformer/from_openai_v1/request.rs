@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::ChatCompletionRequest as OpenAIChatCompletionRequest;
+
+use crate::databricks::serving_endpoints::chat_completion::request::ChatCompletionRequest as DatabricksChatCompletionRequest;
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_databricks_serving_endpoints(
+		&self,
+		context: TransformationContext,
+	) -> Transformation {
+		Transformation {
+			request: DatabricksChatCompletionRequest {
+				messages: self.messages.clone(),
+				max_tokens: self.max_tokens,
+				stream: self.stream,
+				temperature: self.temperature,
+				top_p: self.top_p,
+				top_k: context.top_k,
+				stop: self.stop.clone(),
+				n: self.n,
+				tools: self.tools.clone(),
+				tool_choice: self.tool_choice.clone(),
+				response_format: self.response_format.clone(),
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+			},
+			loss: TransformationLoss {
+				model: self.model.clone(),
+				frequency_penalty: self.frequency_penalty,
+				presence_penalty: self.presence_penalty,
+				seed: self.seed,
+				logit_bias: self.logit_bias.clone(),
+				user: self.user.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	/// The model is chosen by the serving endpoint the request is sent to.
+	pub model: String,
+	pub frequency_penalty: Option<f64>,
+	pub presence_penalty: Option<f64>,
+	pub seed: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub user: Option<String>,
+}
+
+pub struct TransformationContext {
+	pub top_k: Option<u64>,
+}
+
+pub struct Transformation {
+	pub request: DatabricksChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "databricks-meta-llama-3-3-70b-instruct",
+		  "messages": [{ "role": "user", "content": "Hello!" }],
+		  "max_tokens": 128,
+		  "seed": 42,
+		  "presence_penalty": 0.5
+		}))?;
+
+		let data =
+			fx_request.to_databricks_serving_endpoints(TransformationContext { top_k: None });
+
+		assert_eq!(data.request.messages, fx_request.messages);
+		assert_eq!(data.request.max_tokens, Some(128));
+
+		// Check if the model and the parameters Databricks doesn't accept were passed to the loss
+		// object.
+		assert_eq!(data.loss.model, fx_request.model);
+		assert_eq!(data.loss.seed, Some(42));
+		assert_eq!(data.loss.presence_penalty, Some(0.5));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionStop, ChatCompletionTool, ChatCompletionToolChoice,
+};
+
+/// Fireworks serves an OpenAI compatible API, so the messages and tools are the OpenAI ones.
+/// Models are named `accounts/<account>/models/<model>`, see [`model_name`].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// The name of the model to use, e.g. `accounts/fireworks/models/llama-v3p1-8b-instruct`.
+	pub model: String,
+
+	/// A list of messages comprising the conversation so far.
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// The maximum number of tokens to generate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// What to do when the prompt and `max_tokens` don't fit in the context of the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub context_length_exceeded_behavior: Option<ContextLengthExceededBehavior>,
+
+	/// The size, in tokens, to which the prompt is truncated by dropping the oldest messages. The
+	/// system prompt is always kept.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_truncate_len: Option<u64>,
+
+	/// Up to 4 sequences where the API will stop generating further tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<ChatCompletionStop>,
+
+	/// What sampling temperature to use, between 0 and 2.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// An alternative to sampling with temperature, called nucleus sampling.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// Only sample from the top K options for each subsequent token.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// The minimum probability, relative to the most likely token, for a token to be sampled.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub min_p: Option<f64>,
+
+	/// Applies a penalty to repeated tokens. Values above 1 discourage repetition.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub repetition_penalty: Option<f64>,
+
+	/// Number between -2.0 and 2.0. Positive values penalize new tokens based on whether they
+	/// appear in the text so far.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// Number between -2.0 and 2.0. Positive values penalize new tokens based on their existing
+	/// frequency in the text so far.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// Modify the likelihood of specified tokens appearing in the completion.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logit_bias: Option<HashMap<String, i32>>,
+
+	/// Whether to return log probabilities of the output tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<bool>,
+
+	/// The number of most likely tokens to return at each token position.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_logprobs: Option<i64>,
+
+	/// Random seed, for reproducible sampling.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	/// How many chat completion choices to generate for each input message.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub n: Option<u64>,
+
+	/// Whether to stream back partial progress as server-sent events.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// An object specifying the format that the model must output.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// A list of tools the model may call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	/// Controls which (if any) tool is called by the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+
+	/// A unique identifier representing your end-user.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub user: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContextLengthExceededBehavior {
+	/// Lower `max_tokens` so the request fits in the context. This is the default.
+	#[cfg_attr(feature = "serde", serde(rename = "truncate"))]
+	Truncate,
+	/// Fail the request.
+	#[cfg_attr(feature = "serde", serde(rename = "error"))]
+	Error,
+}
+
+/// The full name of a model of the given account, e.g. `accounts/fireworks/models/<model>` for the
+/// serverless models. Names that are already qualified are returned unchanged.
+pub fn model_name(account: &str, model: &str) -> String {
+	if model.starts_with("accounts/") {
+		model.to_string()
+	} else {
+		format!("accounts/{account}/models/{model}")
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_request_fireworks_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "accounts/fireworks/models/llama-v3p1-8b-instruct",
+		  "messages": [{ "role": "user", "content": "Say this is a test" }],
+		  "max_tokens": 16384,
+		  "context_length_exceeded_behavior": "truncate",
+		  "top_k": 40
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.messages.len(), 1);
+		assert_eq!(
+			data.context_length_exceeded_behavior,
+			Some(ContextLengthExceededBehavior::Truncate)
+		);
+		assert_eq!(
+			model_name("fireworks", "llama-v3p1-8b-instruct"),
+			"accounts/fireworks/models/llama-v3p1-8b-instruct"
+		);
+		assert_eq!(model_name("fireworks", &data.model), data.model);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
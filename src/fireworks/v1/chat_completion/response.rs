@@ -0,0 +1,92 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponseChoice, ChatCompletionObjectResponseChoice,
+	ChatCompletionResponseUsagePromptTokensDetails,
+};
+
+/// The choices have the OpenAI shape, while the usage and the top level carry a few extra fields.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponse {
+	pub id: String,
+
+	/// The object type, which is always `chat.completion`.
+	pub object: String,
+
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: u64,
+
+	pub model: String,
+
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+
+	/// Server side timings, only returned when requested with `perf_metrics_in_response`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub perf_metrics: Option<serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	pub id: String,
+
+	/// The object type, which is always `chat.completion.chunk`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub model: String,
+
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	/// Sent with the last chunk, whether or not it was asked for.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsage {
+	pub prompt_tokens: u64,
+	/// Missing when nothing was generated.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub completion_tokens: Option<u64>,
+	pub total_tokens: u64,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_tokens_details: Option<ChatCompletionResponseUsagePromptTokensDetails>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_object_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "chatcmpl-8b0c3d6e-5b0a-4c3b-9a4e-7c1f2e6d9b3a",
+		  "object": "chat.completion",
+		  "created": 1727000000,
+		  "model": "accounts/fireworks/models/llama-v3p1-8b-instruct",
+		  "choices": [{ "index": 0, "finish_reason": "stop", "message": { "role": "assistant", "content": "This is a test." } }],
+		  "usage": { "prompt_tokens": 17, "completion_tokens": 6, "total_tokens": 23, "prompt_tokens_details": { "cached_tokens": 0 } }
+		})
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].message.content, Some("This is a test.".to_string()));
+		assert_eq!(data.usage.and_then(|usage| usage.completion_tokens), Some(6));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,99 @@
+use crate::openai::v1::chat_completion::request::ChatCompletionRequest as OpenAIChatCompletionRequest;
+
+use crate::fireworks::v1::chat_completion::request::{
+	model_name, ChatCompletionRequest as FireworksChatCompletionRequest,
+	ContextLengthExceededBehavior as FireworksContextLengthExceededBehavior,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_fireworks_v1(&self, context: TransformationContext) -> Transformation {
+		Transformation {
+			request: FireworksChatCompletionRequest {
+				model: model_name(&context.account, &self.model),
+				messages: self.messages.clone(),
+				max_tokens: self.max_tokens,
+				context_length_exceeded_behavior: context.context_length_exceeded_behavior,
+				prompt_truncate_len: context.prompt_truncate_len,
+				stop: self.stop.clone(),
+				temperature: self.temperature,
+				top_p: self.top_p,
+				top_k: context.top_k,
+				min_p: context.min_p,
+				repetition_penalty: context.repetition_penalty,
+				presence_penalty: self.presence_penalty,
+				frequency_penalty: self.frequency_penalty,
+				logit_bias: self.logit_bias.clone(),
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				seed: self.seed,
+				n: self.n,
+				stream: self.stream,
+				response_format: self.response_format.clone(),
+				tools: self.tools.clone(),
+				tool_choice: self.tool_choice.clone(),
+				user: self.user.clone(),
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+/// The parameters that only exist on Fireworks, usually set on the connection.
+pub struct TransformationContext {
+	/// The account owning the model, `fireworks` for the serverless models.
+	pub account: String,
+	pub context_length_exceeded_behavior: Option<FireworksContextLengthExceededBehavior>,
+	pub prompt_truncate_len: Option<u64>,
+	pub top_k: Option<u64>,
+	pub min_p: Option<f64>,
+	pub repetition_penalty: Option<f64>,
+}
+
+pub struct Transformation {
+	pub request: FireworksChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "llama-v3p1-8b-instruct",
+		  "messages": [{ "role": "user", "content": "Hello!" }],
+		  "max_tokens": 16384,
+		  "user": "user-1234"
+		}))?;
+
+		let data = fx_request.to_fireworks_v1(TransformationContext {
+			account: "fireworks".to_string(),
+			context_length_exceeded_behavior: Some(FireworksContextLengthExceededBehavior::Error),
+			prompt_truncate_len: None,
+			top_k: None,
+			min_p: None,
+			repetition_penalty: None,
+		});
+
+		assert_eq!(data.request.model, "accounts/fireworks/models/llama-v3p1-8b-instruct");
+		assert_eq!(data.request.messages, fx_request.messages);
+		assert_eq!(
+			data.request.context_length_exceeded_behavior,
+			Some(FireworksContextLengthExceededBehavior::Error)
+		);
+		assert_eq!(data.request.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
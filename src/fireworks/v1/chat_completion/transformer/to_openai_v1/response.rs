@@ -0,0 +1,142 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+};
+
+use crate::fireworks::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as FireworksChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as FireworksChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as FireworksChatCompletionResponseUsage,
+};
+
+// region:    --- Object Response
+impl FireworksChatCompletionObjectResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: self.choices.clone(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: self.usage.as_ref().map(usage).unwrap_or(
+					OpenAIChatCompletionResponseUsage {
+						completion_tokens: 0,
+						prompt_tokens: 0,
+						total_tokens: 0,
+						prompt_tokens_details: None,
+					},
+				),
+				service_tier: None,
+			},
+			loss: TransformationLoss { perf_metrics: self.perf_metrics.clone() },
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub perf_metrics: Option<serde_json::Value>,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Chunk Response
+impl FireworksChatCompletionChunkResponse {
+	pub fn to_openai_v1(&self) -> ChunkTransformation {
+		ChunkTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices: self.choices.clone(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion.chunk".to_string(),
+				usage: self.usage.as_ref().map(usage),
+				service_tier: None,
+			},
+			loss: ChunkTransformationLoss {},
+		}
+	}
+}
+
+pub struct ChunkTransformationLoss {}
+
+pub struct ChunkTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: ChunkTransformationLoss,
+}
+// endregion: --- Chunk Response
+
+fn usage(usage: &FireworksChatCompletionResponseUsage) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: usage.completion_tokens.unwrap_or(0),
+		prompt_tokens: usage.prompt_tokens,
+		total_tokens: usage.total_tokens,
+		prompt_tokens_details: usage.prompt_tokens_details.clone(),
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+	use crate::openai::v1::chat_completion::response::ChatCompletionResponseUsagePromptTokensDetails as OpenAIChatCompletionResponseUsagePromptTokensDetails;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: FireworksChatCompletionObjectResponse = serde_json::from_value(json!({
+		  "id": "chatcmpl-8b0c3d6e-5b0a-4c3b-9a4e-7c1f2e6d9b3a",
+		  "object": "chat.completion",
+		  "created": 1727000000,
+		  "model": "accounts/fireworks/models/llama-v3p1-8b-instruct",
+		  "choices": [{ "index": 0, "finish_reason": "stop", "message": { "role": "assistant", "content": "Hello!" } }],
+		  "usage": { "prompt_tokens": 17, "completion_tokens": 2, "total_tokens": 19, "prompt_tokens_details": { "cached_tokens": 16 } },
+		  "perf_metrics": { "prompt-tokens": 17 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.usage.total_tokens, 19);
+		assert_eq!(
+			data.response.usage.prompt_tokens_details,
+			Some(OpenAIChatCompletionResponseUsagePromptTokensDetails { cached_tokens: Some(16) })
+		);
+
+		// Check if the performance metrics were passed to the loss object.
+		assert_eq!(data.loss.perf_metrics, Some(json!({ "prompt-tokens": 17 })));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_chunk_response_transform_ok() -> Result<()> {
+		let fx_response: FireworksChatCompletionChunkResponse = serde_json::from_value(json!({
+		  "id": "chatcmpl-8b0c3d6e-5b0a-4c3b-9a4e-7c1f2e6d9b3a",
+		  "object": "chat.completion.chunk",
+		  "created": 1727000000,
+		  "model": "accounts/fireworks/models/llama-v3p1-8b-instruct",
+		  "choices": [{ "index": 0, "finish_reason": "length", "delta": {} }],
+		  "usage": { "prompt_tokens": 17, "total_tokens": 17 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.choices[0].finish_reason, Some("length".to_string()));
+		assert_eq!(data.response.usage.map(|usage| usage.completion_tokens), Some(0));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,105 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchEmbedContentsRequest {
+	/// Embed requests for the batch. The model in each of these requests must match the model
+	/// specified in the `batchEmbedContents` call.
+	pub requests: Vec<EmbedContentRequest>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct EmbedContentRequest {
+	/// The model's resource name. This serves as an ID for the Model to use. Format:
+	/// `models/{model}`.
+	pub model: String,
+
+	/// The content to embed. Only the `parts.text` fields will be counted.
+	pub content: EmbedContentRequestContent,
+
+	/// Optional task type for which the embeddings will be used.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub task_type: Option<EmbedContentTaskType>,
+
+	/// An optional title for the text. Only applicable when TaskType is `RETRIEVAL_DOCUMENT`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub title: Option<String>,
+
+	/// Optional reduced dimension for the output embedding. If set, excessive values in the
+	/// output embedding are truncated from the end.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub output_dimensionality: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedContentRequestContent {
+	pub parts: Vec<EmbedContentRequestContentPart>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub role: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedContentRequestContentPart {
+	pub text: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum EmbedContentTaskType {
+	TaskTypeUnspecified,
+	RetrievalQuery,
+	RetrievalDocument,
+	SemanticSimilarity,
+	Classification,
+	Clustering,
+	QuestionAnswering,
+	FactVerification,
+	CodeRetrievalQuery,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_batch_embed_contents_gemini_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "requests": [
+			{
+			  "model": "models/text-embedding-004",
+			  "content": {
+				"parts": [{ "text": "What is the meaning of life?" }]
+			  },
+			  "taskType": "RETRIEVAL_QUERY",
+			  "outputDimensionality": 256
+			}
+		  ]
+		})
+		.to_string();
+
+		let data: BatchEmbedContentsRequest = serde_json::from_str(&fx_request).unwrap();
+
+		assert_eq!(data.requests[0].task_type, Some(EmbedContentTaskType::RetrievalQuery));
+		assert_eq!(data.requests[0].output_dimensionality, Some(256));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,44 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchEmbedContentsResponse {
+	/// The embeddings for each request, in the same order as provided in the batch request.
+	pub embeddings: Vec<ContentEmbedding>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentEmbedding {
+	/// The embedding values.
+	pub values: Vec<f64>,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_batch_embed_contents_response_gemini_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "embeddings": [
+			{ "values": [0.013168523, -0.008711934, -0.046782676] },
+			{ "values": [0.0070524803, -0.0012701169, -0.0458917] }
+		  ]
+		})
+		.to_string();
+
+		let data: BatchEmbedContentsResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.embeddings.len(), 2);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
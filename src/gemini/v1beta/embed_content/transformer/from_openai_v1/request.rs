@@ -0,0 +1,155 @@
+use crate::openai::v1::embeddings::request::{
+	EmbeddingsRequest as OpenAIEmbeddingsRequest,
+	EmbeddingsRequestInput as OpenAIEmbeddingsRequestInput,
+};
+
+use crate::gemini::v1beta::embed_content::request::{
+	BatchEmbedContentsRequest as GeminiBatchEmbedContentsRequest,
+	EmbedContentRequest as GeminiEmbedContentRequest,
+	EmbedContentRequestContent as GeminiEmbedContentRequestContent,
+	EmbedContentRequestContentPart as GeminiEmbedContentRequestContentPart,
+	EmbedContentTaskType as GeminiEmbedContentTaskType,
+};
+
+impl OpenAIEmbeddingsRequest {
+	pub fn to_gemini_v1beta(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		let texts = match self.input.clone() {
+			OpenAIEmbeddingsRequestInput::String(value) => vec![value],
+			OpenAIEmbeddingsRequestInput::ArrayString(values) => values,
+			OpenAIEmbeddingsRequestInput::ArrayInt(_)
+			| OpenAIEmbeddingsRequestInput::ArrayArrayInt(_) =>
+				return Err(TransformationError::TokenInputNotSupported),
+		};
+
+		// Gemini only returns float values, so any other encoding is reported as lost.
+		let encoding_format = match self.encoding_format.as_deref() {
+			None | Some("float") => None,
+			Some(_) => self.encoding_format.clone(),
+		};
+
+		// Gemini expects the model's resource name, which is prefixed by `models/`.
+		let model = if self.model.starts_with("models/") {
+			self.model.clone()
+		} else {
+			format!("models/{}", self.model)
+		};
+
+		Ok(Transformation {
+			request: GeminiBatchEmbedContentsRequest {
+				requests: texts
+					.into_iter()
+					.map(|text| GeminiEmbedContentRequest {
+						model: model.clone(),
+						content: GeminiEmbedContentRequestContent {
+							parts: vec![GeminiEmbedContentRequestContentPart { text }],
+							role: None,
+						},
+						task_type: context.task_type.clone(),
+						title: context.title.clone(),
+						output_dimensionality: self.dimensions,
+					})
+					.collect(),
+			},
+			loss: TransformationLoss { encoding_format, user: self.user.clone() },
+		})
+	}
+}
+
+pub struct TransformationLoss {
+	pub encoding_format: Option<String>,
+	pub user: Option<String>,
+}
+
+pub struct TransformationContext {
+	pub task_type: Option<GeminiEmbedContentTaskType>,
+	pub title: Option<String>,
+}
+
+pub struct Transformation {
+	pub request: GeminiBatchEmbedContentsRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// Gemini only embeds text, so token array inputs can't be forwarded.
+	TokenInputNotSupported,
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::TokenInputNotSupported =>
+				write!(f, "token array inputs are not supported by Gemini"),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayString(vec![
+				"hello".to_string(),
+				"world".to_string(),
+			]),
+			model: "text-embedding-004".to_string(),
+			encoding_format: Some("base64".to_string()),
+			dimensions: Some(256),
+			user: Some("user-1234".to_string()),
+		};
+
+		let data = fx_request.to_gemini_v1beta(TransformationContext {
+			task_type: Some(GeminiEmbedContentTaskType::RetrievalDocument),
+			title: None,
+		})?;
+
+		// Check if one request was created per input, with the model resource name.
+		assert_eq!(data.request.requests.len(), 2);
+		assert_eq!(data.request.requests[0].model, "models/text-embedding-004");
+		assert_eq!(data.request.requests[1].content.parts[0].text, "world");
+		assert_eq!(
+			data.request.requests[1].task_type,
+			Some(GeminiEmbedContentTaskType::RetrievalDocument)
+		);
+		assert_eq!(data.request.requests[1].output_dimensionality, Some(256));
+
+		// Check if the parameters Gemini can't honor were passed to the loss object.
+		assert_eq!(data.loss.encoding_format, Some("base64".to_string()));
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_token_input_request_transform_fail() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayArrayInt(vec![vec![1, 2, 3]]),
+			model: "models/text-embedding-004".to_string(),
+			encoding_format: None,
+			dimensions: None,
+			user: None,
+		};
+
+		let data =
+			fx_request.to_gemini_v1beta(TransformationContext { task_type: None, title: None });
+
+		assert_eq!(data.err(), Some(TransformationError::TokenInputNotSupported));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
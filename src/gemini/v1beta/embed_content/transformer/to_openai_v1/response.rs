@@ -0,0 +1,80 @@
+use crate::openai::v1::embeddings::response::{
+	EmbeddingsResponse as OpenAIEmbeddingsResponse,
+	EmbeddingsResponseData as OpenAIEmbeddingsResponseData,
+	EmbeddingsResponseEmbedding as OpenAIEmbeddingsResponseEmbedding,
+	EmbeddingsResponseUsage as OpenAIEmbeddingsResponseUsage,
+};
+
+use crate::gemini::v1beta::embed_content::response::BatchEmbedContentsResponse as GeminiBatchEmbedContentsResponse;
+
+impl GeminiBatchEmbedContentsResponse {
+	pub fn to_openai_v1(&self, context: TransformationContext) -> Transformation {
+		Transformation {
+			response: OpenAIEmbeddingsResponse {
+				object: "list".to_string(),
+				data: self
+					.embeddings
+					.clone()
+					.into_iter()
+					.enumerate()
+					.map(|(index, embedding)| OpenAIEmbeddingsResponseData {
+						object: "embedding".to_string(),
+						embedding: OpenAIEmbeddingsResponseEmbedding::Float(embedding.values),
+						index: index as u64,
+					})
+					.collect(),
+				model: context.model,
+				// Gemini doesn't report token usage for embeddings.
+				usage: OpenAIEmbeddingsResponseUsage { prompt_tokens: 0, total_tokens: 0 },
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct TransformationContext {
+	pub model: String,
+}
+
+pub struct Transformation {
+	pub response: OpenAIEmbeddingsResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use crate::gemini::v1beta::embed_content::response::ContentEmbedding;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_response_transform_ok() -> Result<()> {
+		let fx_response = GeminiBatchEmbedContentsResponse {
+			embeddings: vec![
+				ContentEmbedding { values: vec![0.1, 0.2] },
+				ContentEmbedding { values: vec![0.3, 0.4] },
+			],
+		};
+
+		let data = fx_response
+			.to_openai_v1(TransformationContext { model: "text-embedding-004".to_string() });
+
+		assert_eq!(data.response.model, "text-embedding-004");
+		assert_eq!(data.response.data.len(), 2);
+		assert_eq!(data.response.data[1].index, 1);
+		assert_eq!(
+			data.response.data[1].embedding,
+			OpenAIEmbeddingsResponseEmbedding::Float(vec![0.3, 0.4])
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,62 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponse {
+	pub error: ErrorResponseError,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponseError {
+	/// The HTTP status code of the error.
+	pub code: u16,
+
+	/// A developer-facing error message.
+	pub message: String,
+
+	/// The canonical error status, such as `INVALID_ARGUMENT` or `RESOURCE_EXHAUSTED`.
+	pub status: String,
+
+	/// A list of messages that carry the error details.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub details: Option<Vec<serde_json::Value>>,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_error_response_gemini_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "error": {
+			"code": 400,
+			"message": "API key not valid. Please pass a valid API key.",
+			"status": "INVALID_ARGUMENT",
+			"details": [
+			  {
+				"@type": "type.googleapis.com/google.rpc.ErrorInfo",
+				"reason": "API_KEY_INVALID",
+				"domain": "googleapis.com"
+			  }
+			]
+		  }
+		})
+		.to_string();
+
+		let data: ErrorResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.error.code, 400);
+		assert_eq!(data.error.status, "INVALID_ARGUMENT");
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,87 @@
+use serde_json::json;
+
+use crate::openai::v1::error::response::{
+	ErrorResponse as OpenAIErrorResponse, ErrorResponseError as OpenAIErrorResponseError,
+};
+
+use crate::gemini::v1beta::error::response::ErrorResponse as GeminiErrorResponse;
+
+impl GeminiErrorResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		let error = &self.error;
+
+		let (r#type, code) = match error.status.as_str() {
+			"UNAUTHENTICATED" => ("authentication_error", Some("invalid_api_key")),
+			"PERMISSION_DENIED" => ("permission_error", None),
+			"NOT_FOUND" => ("invalid_request_error", Some("not_found")),
+			"RESOURCE_EXHAUSTED" => ("rate_limit_error", Some("rate_limit_exceeded")),
+			"INTERNAL" | "UNAVAILABLE" | "DEADLINE_EXCEEDED" => ("server_error", None),
+			_ => ("invalid_request_error", None),
+		};
+
+		Transformation {
+			response: OpenAIErrorResponse {
+				error: OpenAIErrorResponseError {
+					message: error.message.clone(),
+					r#type: r#type.to_string(),
+					param: None,
+					code: code.map(|code| code.to_string()),
+					vendor: Some(json!({
+						"gemini": {
+							"code": error.code,
+							"message": error.message,
+							"status": error.status,
+							"details": error.details,
+						}
+					})),
+				},
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct Transformation {
+	pub response: OpenAIErrorResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use crate::gemini::v1beta::error::response::ErrorResponseError as GeminiErrorResponseError;
+
+	use super::*;
+
+	#[test]
+	fn test_resource_exhausted_error_transform_ok() -> Result<()> {
+		let fx_response = GeminiErrorResponse {
+			error: GeminiErrorResponseError {
+				code: 429,
+				message: "Resource has been exhausted".to_string(),
+				status: "RESOURCE_EXHAUSTED".to_string(),
+				details: None,
+			},
+		};
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.error.message, "Resource has been exhausted");
+		assert_eq!(data.response.error.r#type, "rate_limit_error");
+		assert_eq!(data.response.error.code, Some("rate_limit_exceeded".to_string()));
+
+		// Check if the original error was attached under the vendor field.
+		let vendor = data.response.error.vendor.unwrap();
+		assert_eq!(vendor["gemini"]["status"], json!("RESOURCE_EXHAUSTED"));
+		assert_eq!(vendor["gemini"]["code"], json!(429));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,319 @@
+/// The request of `models/{model}:generateContent` and `models/{model}:streamGenerateContent`.
+/// The model is part of the URL, see [`generate_content_path`], so it has no model field.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct GenerateContentRequest {
+	/// The content of the current conversation with the model. For single-turn queries, this is a
+	/// single instance. For multi-turn queries, this is a repeated field that contains the
+	/// conversation history and the latest request.
+	pub contents: Vec<Content>,
+
+	/// Developer set system instructions. Currently, text only.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system_instruction: Option<Content>,
+
+	/// A list of tools the model may use to generate the next response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<Tool>>,
+
+	/// Tool configuration for any tool specified in the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_config: Option<ToolConfig>,
+
+	/// A list of unique safety settings for blocking unsafe content.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub safety_settings: Option<Vec<serde_json::Value>>,
+
+	/// Configuration options for model generation and outputs.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub generation_config: Option<GenerationConfig>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Content {
+	/// The producer of the content, either `user` or `model`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub role: Option<String>,
+
+	/// Ordered parts that constitute a single message.
+	pub parts: Vec<Part>,
+}
+
+/// A part holds exactly one kind of data, identified by the name of its field.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum Part {
+	TextPart {
+		text: String,
+		/// Whether the part is a thought of the model, for thinking models.
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		thought: Option<bool>,
+	},
+	InlineDataPart {
+		#[cfg_attr(feature = "serde", serde(rename = "inlineData"))]
+		inline_data: Blob,
+	},
+	FileDataPart {
+		#[cfg_attr(feature = "serde", serde(rename = "fileData"))]
+		file_data: FileData,
+	},
+	FunctionCallPart {
+		#[cfg_attr(feature = "serde", serde(rename = "functionCall"))]
+		function_call: FunctionCall,
+	},
+	FunctionResponsePart {
+		#[cfg_attr(feature = "serde", serde(rename = "functionResponse"))]
+		function_response: FunctionResponse,
+	},
+}
+
+/// Raw media bytes.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct Blob {
+	/// The IANA standard MIME type of the source data, e.g. `image/png`.
+	pub mime_type: String,
+	/// The base64 encoded bytes.
+	pub data: String,
+}
+
+/// URI based data, e.g. a file uploaded with the File API or, on Vertex AI, a `gs://` URI.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct FileData {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub mime_type: Option<String>,
+	pub file_uri: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionCall {
+	/// Only returned by some models.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub id: Option<String>,
+	/// The name of the function to call.
+	pub name: String,
+	/// The function parameters and values in JSON object format.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub args: Option<serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionResponse {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub id: Option<String>,
+	/// The name of the function that was called.
+	pub name: String,
+	/// The function response in JSON object format.
+	pub response: serde_json::Value,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct Tool {
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub function_declarations: Option<Vec<FunctionDeclaration>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionDeclaration {
+	pub name: String,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub description: Option<String>,
+	/// The parameters of the function, as an OpenAPI schema object.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub parameters: Option<serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct ToolConfig {
+	pub function_calling_config: FunctionCallingConfig,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct FunctionCallingConfig {
+	pub mode: FunctionCallingMode,
+	/// The functions the model may call, only allowed with the `ANY` mode.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub allowed_function_names: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum FunctionCallingMode {
+	/// The model decides whether to call a function or to answer in natural language.
+	Auto,
+	/// The model always calls a function.
+	Any,
+	/// The model never calls a function.
+	None,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct GenerationConfig {
+	/// Up to 5 character sequences that will stop output generation.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop_sequences: Option<Vec<String>>,
+
+	/// The MIME type of the generated text, `text/plain` or `application/json`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_mime_type: Option<String>,
+
+	/// The schema of the generated JSON, as an OpenAPI schema object.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_schema: Option<serde_json::Value>,
+
+	/// Number of generated responses to return.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub candidate_count: Option<u64>,
+
+	/// The maximum number of tokens to include in a response candidate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_output_tokens: Option<u64>,
+
+	/// Controls the randomness of the output, between 0 and 2.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// The maximum cumulative probability of tokens to consider when sampling.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// The maximum number of tokens to consider when sampling.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// Seed used in decoding.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	/// Penalizes tokens that already appeared in the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// Penalizes tokens proportionally to the number of times they appeared in the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// If true, export the log probabilities of the generated tokens in the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_logprobs: Option<bool>,
+
+	/// The number of top log probabilities to return at each decoding step.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<i64>,
+}
+
+/// The path, relative to `https://generativelanguage.googleapis.com`, that generates content with
+/// the given model. Streaming uses server-sent events, which have to be asked for with `alt=sse`.
+pub fn generate_content_path(model: &str, stream: bool) -> String {
+	let model = model.strip_prefix("models/").unwrap_or(model);
+	if stream {
+		format!("/v1beta/models/{model}:streamGenerateContent?alt=sse")
+	} else {
+		format!("/v1beta/models/{model}:generateContent")
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_generate_content_gemini_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "systemInstruction": { "parts": [{ "text": "You are a cat. Your name is Neko." }] },
+		  "contents": [
+			{ "role": "user", "parts": [{ "text": "What's the weather in Boston?" }, { "inlineData": { "mimeType": "image/png", "data": "iVBORw0KGgo=" } }] },
+			{ "role": "model", "parts": [{ "functionCall": { "name": "get_weather", "args": { "location": "Boston" } } }] },
+			{ "role": "user", "parts": [{ "functionResponse": { "name": "get_weather", "response": { "content": "10 degrees" } } }] }
+		  ],
+		  "tools": [{ "functionDeclarations": [{ "name": "get_weather", "parameters": { "type": "object" } }] }],
+		  "toolConfig": { "functionCallingConfig": { "mode": "ANY", "allowedFunctionNames": ["get_weather"] } },
+		  "generationConfig": { "stopSequences": ["Title"], "temperature": 1.0, "maxOutputTokens": 800, "topP": 0.8, "topK": 10 }
+		})
+		.to_string();
+
+		let data: GenerateContentRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.contents.len(), 3);
+		assert_eq!(
+			data.contents[0].parts[1],
+			Part::InlineDataPart {
+				inline_data: Blob {
+					mime_type: "image/png".to_string(),
+					data: "iVBORw0KGgo=".to_string()
+				}
+			}
+		);
+		assert_eq!(
+			data.contents[1].parts[0],
+			Part::FunctionCallPart {
+				function_call: FunctionCall {
+					id: None,
+					name: "get_weather".to_string(),
+					args: Some(json!({ "location": "Boston" }))
+				}
+			}
+		);
+		assert_eq!(
+			data.tool_config.map(|config| config.function_calling_config.mode),
+			Some(FunctionCallingMode::Any)
+		);
+		assert_eq!(data.generation_config.and_then(|config| config.max_output_tokens), Some(800));
+		assert_eq!(
+			generate_content_path("models/gemini-2.0-flash", true),
+			"/v1beta/models/gemini-2.0-flash:streamGenerateContent?alt=sse"
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
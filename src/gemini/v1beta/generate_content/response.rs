@@ -0,0 +1,147 @@
+use crate::gemini::v1beta::generate_content::request::Content;
+
+/// The response of `generateContent`. When streaming, every event is one of these objects, each
+/// carrying the next part of the candidates.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct GenerateContentResponse {
+	/// Candidate responses from the model. Empty when the prompt was blocked.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub candidates: Vec<Candidate>,
+
+	/// The prompt's feedback related to the content filters.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_feedback: Option<serde_json::Value>,
+
+	/// Metadata on the generation request's token usage.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage_metadata: Option<UsageMetadata>,
+
+	/// The model version used to generate the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub model_version: Option<String>,
+
+	/// The identifier of the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_id: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct Candidate {
+	/// Generated content returned from the model. Missing when the candidate was blocked.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content: Option<Content>,
+
+	/// The reason why the model stopped generating tokens, e.g. `STOP`, `MAX_TOKENS` or `SAFETY`.
+	/// Only set on the last event when streaming.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub finish_reason: Option<String>,
+
+	/// List of ratings for the safety of a response candidate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub safety_ratings: Option<Vec<serde_json::Value>>,
+
+	/// Index of the candidate in the list of response candidates.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub index: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "camelCase")
+)]
+pub struct UsageMetadata {
+	/// Number of tokens in the prompt, including the cached content.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub prompt_token_count: u64,
+
+	/// Number of tokens in the cached part of the prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cached_content_token_count: Option<u64>,
+
+	/// Total number of tokens across all the generated response candidates.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub candidates_token_count: Option<u64>,
+
+	/// Number of tokens of thoughts, for thinking models.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub thoughts_token_count: Option<u64>,
+
+	/// Total token count for the generation request.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub total_token_count: u64,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use crate::gemini::v1beta::generate_content::request::Part;
+	use serde_json::json;
+
+	#[test]
+	fn test_generate_content_response_gemini_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "candidates": [
+			{
+			  "content": { "parts": [{ "text": "Hello! How can I help you today?" }], "role": "model" },
+			  "finishReason": "STOP",
+			  "index": 0,
+			  "safetyRatings": [{ "category": "HARM_CATEGORY_HARASSMENT", "probability": "NEGLIGIBLE" }]
+			}
+		  ],
+		  "usageMetadata": { "promptTokenCount": 4, "candidatesTokenCount": 9, "totalTokenCount": 13 },
+		  "modelVersion": "gemini-2.0-flash"
+		})
+		.to_string();
+
+		let data: GenerateContentResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.candidates[0].finish_reason, Some("STOP".to_string()));
+		assert_eq!(
+			data.candidates[0].content.as_ref().map(|content| content.parts[0].clone()),
+			Some(Part::TextPart {
+				text: "Hello! How can I help you today?".to_string(),
+				thought: None
+			})
+		);
+		assert_eq!(data.usage_metadata.map(|usage| usage.total_token_count), Some(13));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_generate_content_response_gemini_blocked_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "promptFeedback": { "blockReason": "SAFETY" },
+		  "usageMetadata": { "promptTokenCount": 8, "totalTokenCount": 8 }
+		})
+		.to_string();
+
+		let data: GenerateContentResponse = serde_json::from_str(&fx_response)?;
+
+		assert!(data.candidates.is_empty());
+		assert_eq!(data.prompt_feedback, Some(json!({ "blockReason": "SAFETY" })));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
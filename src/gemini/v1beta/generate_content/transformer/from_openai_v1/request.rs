@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage as OpenAIChatCompletionMessage,
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop, ChatCompletionTool as OpenAIChatCompletionTool,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+	ChatCompletionToolChoiceObject as OpenAIChatCompletionToolChoiceObject,
+	UserMessageContent as OpenAIUserMessageContent,
+	UserMessageContentPart as OpenAIUserMessageContentPart,
+};
+
+use crate::gemini::v1beta::generate_content::request::{
+	Blob as GeminiBlob, Content as GeminiContent, FunctionCall as GeminiFunctionCall,
+	FunctionCallingConfig as GeminiFunctionCallingConfig,
+	FunctionCallingMode as GeminiFunctionCallingMode,
+	FunctionDeclaration as GeminiFunctionDeclaration, FunctionResponse as GeminiFunctionResponse,
+	GenerateContentRequest as GeminiGenerateContentRequest,
+	GenerationConfig as GeminiGenerationConfig, Part as GeminiPart, Tool as GeminiTool,
+	ToolConfig as GeminiToolConfig,
+};
+
+impl OpenAIChatCompletionRequest {
+	/// The same request is used by the Gemini API and by Vertex AI, see
+	/// [`crate::gemini::v1beta::vertex`] for the Vertex URL scheme and authentication.
+	pub fn to_gemini_v1beta(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		let mut system = Vec::<GeminiPart>::new();
+		let mut image_urls = Vec::<String>::new();
+		// Gemini identifies function responses by the function name instead of the tool call id.
+		let mut tool_names = HashMap::<String, String>::new();
+		let mut contents = Vec::<GeminiContent>::new();
+
+		for message in self.messages.clone() {
+			let (role, parts) = match message {
+				OpenAIChatCompletionMessage::SystemMessage { content, .. } => {
+					system.push(text_part(content));
+					continue;
+				},
+				OpenAIChatCompletionMessage::UserMessage { content, .. } => (
+					"user",
+					match content {
+						OpenAIUserMessageContent::TextContent(text) => vec![text_part(text)],
+						OpenAIUserMessageContent::ArrayContentParts(parts) => {
+							let mut gemini_parts = Vec::new();
+							for part in parts {
+								match part {
+									OpenAIUserMessageContentPart::TextContentPart { text } =>
+										gemini_parts.push(text_part(text)),
+									OpenAIUserMessageContentPart::ImageContentPart {
+										image_url,
+									} => match image_url
+										.url
+										.strip_prefix("data:")
+										.and_then(|data_url| data_url.split_once(";base64,"))
+									{
+										Some((mime_type, data)) =>
+											gemini_parts.push(GeminiPart::InlineDataPart {
+												inline_data: GeminiBlob {
+													mime_type: mime_type.to_string(),
+													data: data.to_string(),
+												},
+											}),
+										// Gemini only fetches files it hosts itself.
+										None => image_urls.push(image_url.url),
+									},
+								}
+							}
+							gemini_parts
+						},
+					},
+				),
+				OpenAIChatCompletionMessage::AssistantMessage { content, tool_calls, .. } => {
+					let mut parts = Vec::new();
+					if let Some(text) = content.filter(|text| !text.is_empty()) {
+						parts.push(text_part(text));
+					}
+					for call in tool_calls.unwrap_or_default() {
+						let args = call.function.arguments_object().ok_or_else(|| {
+							TransformationError::InvalidToolCallArguments {
+								tool_call_id: call.id.clone(),
+							}
+						})?;
+						tool_names.insert(call.id, call.function.name.clone());
+						parts.push(GeminiPart::FunctionCallPart {
+							function_call: GeminiFunctionCall {
+								id: None,
+								name: call.function.name,
+								args: Some(args),
+							},
+						});
+					}
+					("model", parts)
+				},
+				OpenAIChatCompletionMessage::ToolMessage { content, tool_call_id } => (
+					"user",
+					vec![GeminiPart::FunctionResponsePart {
+						function_response: GeminiFunctionResponse {
+							id: None,
+							name: tool_names.get(&tool_call_id).cloned().ok_or(
+								TransformationError::UnknownToolCall {
+									tool_call_id: tool_call_id.clone(),
+								},
+							)?,
+							// The response has to be an object, so other results are wrapped.
+							response: serde_json::from_str(&content)
+								.ok()
+								.filter(serde_json::Value::is_object)
+								.unwrap_or_else(|| serde_json::json!({ "content": content })),
+						},
+					}],
+				),
+			};
+
+			if parts.is_empty() {
+				continue;
+			}
+
+			// The function responses of parallel calls have to be sent in a single turn, so
+			// consecutive messages with the same role are merged.
+			match contents.last_mut() {
+				Some(GeminiContent { role: Some(last_role), parts: last_parts })
+					if last_role == role =>
+					last_parts.extend(parts),
+				_ => contents.push(GeminiContent { role: Some(role.to_string()), parts }),
+			}
+		}
+
+		let (tool_config, tool_choice_loss) = match self.tool_choice.clone() {
+			None => (None, None),
+			Some(OpenAIChatCompletionToolChoice::StringChoice(value)) => {
+				let mode = match value.as_str() {
+					"auto" => Some(GeminiFunctionCallingMode::Auto),
+					"none" => Some(GeminiFunctionCallingMode::None),
+					"required" => Some(GeminiFunctionCallingMode::Any),
+					_ => None,
+				};
+				match mode {
+					Some(mode) => (Some(tool_config(mode, None)), None),
+					None => (None, Some(OpenAIChatCompletionToolChoice::StringChoice(value))),
+				}
+			},
+			Some(OpenAIChatCompletionToolChoice::FunctionChoice(
+				OpenAIChatCompletionToolChoiceObject::FunctionTool { function },
+			)) =>
+				(Some(tool_config(GeminiFunctionCallingMode::Any, Some(vec![function.name]))), None),
+		};
+
+		let (response_mime_type, response_schema, response_format_loss) = match self
+			.response_format
+			.as_ref()
+			.and_then(|format| format.get("type"))
+			.and_then(|value| value.as_str())
+		{
+			None | Some("text") => (None, None, None),
+			Some("json_object") => (Some("application/json".to_string()), None, None),
+			Some("json_schema") => (
+				Some("application/json".to_string()),
+				self.response_format
+					.as_ref()
+					.and_then(|format| format.get("json_schema"))
+					.and_then(|value| value.get("schema"))
+					.cloned(),
+				None,
+			),
+			Some(_) => (None, None, self.response_format.clone()),
+		};
+
+		let generation_config = GeminiGenerationConfig {
+			stop_sequences: self.stop.clone().map(|stop| match stop {
+				OpenAIChatCompletionStop::StringStop(v) => vec![v],
+				OpenAIChatCompletionStop::ArrayStop(v) => v,
+			}),
+			response_mime_type,
+			response_schema,
+			candidate_count: self.n,
+			max_output_tokens: self.max_tokens,
+			temperature: self.temperature,
+			top_p: self.top_p,
+			top_k: context.top_k,
+			seed: self.seed,
+			presence_penalty: self.presence_penalty,
+			frequency_penalty: self.frequency_penalty,
+			response_logprobs: self.logprobs,
+			logprobs: self.top_logprobs,
+		};
+
+		Ok(Transformation {
+			request: GeminiGenerateContentRequest {
+				contents,
+				system_instruction: if system.is_empty() {
+					None
+				} else {
+					Some(GeminiContent { role: None, parts: system })
+				},
+				tools: self.tools.clone().map(|tls| {
+					vec![GeminiTool {
+						function_declarations: Some(
+							tls.into_iter()
+								.map(|tool| match tool {
+									OpenAIChatCompletionTool::FunctionTool { function } =>
+										GeminiFunctionDeclaration {
+											name: function.name,
+											description: function.description,
+											parameters: function.parameters,
+										},
+								})
+								.collect(),
+						),
+					}]
+				}),
+				tool_config,
+				safety_settings: context.safety_settings,
+				generation_config: Some(generation_config)
+					.filter(|config| *config != GeminiGenerationConfig::default()),
+			},
+			loss: TransformationLoss {
+				model: self.model.clone(),
+				logit_bias: self.logit_bias.clone(),
+				user: self.user.clone(),
+				tool_choice: tool_choice_loss,
+				response_format: response_format_loss,
+				image_urls,
+			},
+		})
+	}
+}
+
+fn text_part(text: String) -> GeminiPart {
+	GeminiPart::TextPart { text, thought: None }
+}
+
+fn tool_config(
+	mode: GeminiFunctionCallingMode,
+	allowed_function_names: Option<Vec<String>>,
+) -> GeminiToolConfig {
+	GeminiToolConfig {
+		function_calling_config: GeminiFunctionCallingConfig { mode, allowed_function_names },
+	}
+}
+
+pub struct TransformationLoss {
+	/// The model is part of the URL, see
+	/// [`generate_content_path`](crate::gemini::v1beta::generate_content::request::generate_content_path).
+	pub model: String,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub user: Option<String>,
+	/// Set when the tool choice has no Gemini equivalent.
+	pub tool_choice: Option<OpenAIChatCompletionToolChoice>,
+	/// Set when the response format has no Gemini equivalent.
+	pub response_format: Option<serde_json::Value>,
+	/// Image URLs that were dropped, as Gemini only accepts inline images and files it hosts.
+	pub image_urls: Vec<String>,
+}
+
+pub struct TransformationContext {
+	pub top_k: Option<u64>,
+	pub safety_settings: Option<Vec<serde_json::Value>>,
+}
+
+pub struct Transformation {
+	pub request: GeminiGenerateContentRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// The arguments of an assistant tool call are not a valid JSON object.
+	InvalidToolCallArguments { tool_call_id: String },
+	/// A tool message answers a tool call that is not in the conversation, so the name of the
+	/// function can't be known.
+	UnknownToolCall { tool_call_id: String },
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::InvalidToolCallArguments { tool_call_id } =>
+				write!(f, "arguments of tool call `{tool_call_id}` are not a valid JSON object"),
+			TransformationError::UnknownToolCall { tool_call_id } =>
+				write!(f, "tool call `{tool_call_id}` is not in the conversation"),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	fn fx_context() -> TransformationContext {
+		TransformationContext { top_k: None, safety_settings: None }
+	}
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "gemini-2.0-flash",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{
+			  "role": "user",
+			  "content": [
+				{ "type": "text", "text": "What's in these images?" },
+				{ "type": "image_url", "image_url": { "url": "data:image/png;base64,iVBORw0KGgo=" } },
+				{ "type": "image_url", "image_url": { "url": "https://example.com/image.jpg" } }
+			  ]
+			}
+		  ],
+		  "max_tokens": 100,
+		  "response_format": { "type": "json_object" },
+		  "logit_bias": { "50256": -100 }
+		}))?;
+
+		let data = fx_request.to_gemini_v1beta(fx_context())?;
+
+		assert_eq!(
+			data.request.system_instruction,
+			Some(GeminiContent {
+				role: None,
+				parts: vec![text_part("You are a helpful assistant.".to_string())]
+			})
+		);
+		assert_eq!(data.request.contents.len(), 1);
+		assert_eq!(data.request.contents[0].parts.len(), 2);
+		let config = data.request.generation_config.unwrap_or_default();
+		assert_eq!(config.max_output_tokens, Some(100));
+		assert_eq!(config.response_mime_type, Some("application/json".to_string()));
+
+		// Check if the model, the image URL and the logit bias were passed to the loss object.
+		assert_eq!(data.loss.model, "gemini-2.0-flash");
+		assert_eq!(data.loss.image_urls, vec!["https://example.com/image.jpg".to_string()]);
+		assert!(data.loss.logit_bias.is_some());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_messages_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "gemini-2.0-flash",
+		  "messages": [
+			{ "role": "user", "content": "What's the weather in Boston and Paris?" },
+			{
+			  "role": "assistant",
+			  "tool_calls": [
+				{ "id": "call_1", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\": \"Boston\"}" } },
+				{ "id": "call_2", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\": \"Paris\"}" } }
+			  ]
+			},
+			{ "role": "tool", "content": "10 degrees", "tool_call_id": "call_1" },
+			{ "role": "tool", "content": "{\"degrees\": 15}", "tool_call_id": "call_2" }
+		  ],
+		  "tools": [{ "type": "function", "function": { "name": "get_weather" } }],
+		  "tool_choice": { "type": "function", "function": { "name": "get_weather" } }
+		}))?;
+
+		let data = fx_request.to_gemini_v1beta(fx_context())?;
+
+		assert_eq!(
+			data.request.tool_config,
+			Some(tool_config(
+				GeminiFunctionCallingMode::Any,
+				Some(vec!["get_weather".to_string()])
+			))
+		);
+		assert_eq!(data.request.generation_config, None);
+
+		// Check if the function responses were merged into a single user turn.
+		assert_eq!(data.request.contents.len(), 3);
+		assert_eq!(data.request.contents[1].role, Some("model".to_string()));
+		assert_eq!(
+			data.request.contents[2].parts,
+			vec![
+				GeminiPart::FunctionResponsePart {
+					function_response: GeminiFunctionResponse {
+						id: None,
+						name: "get_weather".to_string(),
+						response: json!({ "content": "10 degrees" })
+					}
+				},
+				GeminiPart::FunctionResponsePart {
+					function_response: GeminiFunctionResponse {
+						id: None,
+						name: "get_weather".to_string(),
+						response: json!({ "degrees": 15 })
+					}
+				},
+			]
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
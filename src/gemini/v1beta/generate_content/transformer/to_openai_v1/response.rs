@@ -0,0 +1,503 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_json::json;
+
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as OpenAIChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceDelta as OpenAIChatCompletionChunkResponseChoiceDelta,
+	ChatCompletionChunkResponseChoiceFunctionToolCall as OpenAIChatCompletionChunkResponseChoiceFunctionToolCall,
+	ChatCompletionChunkResponseChoiceToolCall as OpenAIChatCompletionChunkResponseChoiceToolCall,
+	ChatCompletionChunkResponseChoiceToolCallType as OpenAIChatCompletionChunkResponseChoiceToolCallType,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionObjectResponseChoice as OpenAIChatCompletionObjectResponseChoice,
+	ChatCompletionObjectResponseChoiceMessage as OpenAIChatCompletionObjectResponseChoiceMessage,
+	ChatCompletionObjectResponseChoiceToolCall as OpenAIChatCompletionObjectResponseChoiceToolCall,
+	ChatCompletionResponseChoiceFunctionToolCall as OpenAIChatCompletionResponseChoiceFunctionToolCall,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+	ChatCompletionResponseUsagePromptTokensDetails as OpenAIChatCompletionResponseUsagePromptTokensDetails,
+};
+
+use crate::openai::v1::error::response::{
+	ErrorResponse as OpenAIErrorResponse, ErrorResponseError as OpenAIErrorResponseError,
+};
+
+use crate::gemini::v1beta::generate_content::{
+	request::{FunctionCall as GeminiFunctionCall, Part as GeminiPart},
+	response::{
+		Candidate as GeminiCandidate, GenerateContentResponse as GeminiGenerateContentResponse,
+		UsageMetadata as GeminiUsageMetadata,
+	},
+};
+
+// region:    --- Object Response
+impl GeminiGenerateContentResponse {
+	pub fn to_openai_v1(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		blocked_prompt(self)?;
+
+		let id = self.response_id.clone().unwrap_or(context.id);
+		let mut thinking = Vec::<String>::new();
+
+		let choices = self
+			.candidates
+			.iter()
+			.enumerate()
+			.map(|(position, candidate)| {
+				let index = candidate.index.unwrap_or(position as u64);
+				let parts = candidate_parts(candidate);
+				thinking.extend(thoughts(&parts));
+				let tool_calls: Vec<OpenAIChatCompletionObjectResponseChoiceToolCall> =
+					function_calls(&parts)
+						.into_iter()
+						.enumerate()
+						.map(|(call_index, call)| {
+							OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+								id: tool_call_id(&id, index, call_index, &call),
+								function: OpenAIChatCompletionResponseChoiceFunctionToolCall {
+									name: call.name,
+									arguments: arguments(&call.args),
+								},
+							}
+						})
+						.collect();
+
+				OpenAIChatCompletionObjectResponseChoice {
+					finish_reason: finish_reason(
+						candidate.finish_reason.as_deref(),
+						!tool_calls.is_empty(),
+					),
+					index,
+					message: OpenAIChatCompletionObjectResponseChoiceMessage {
+						content: Some(text(&parts)).filter(|content| !content.is_empty()),
+						role: "assistant".to_string(),
+						tool_calls: if tool_calls.is_empty() { None } else { Some(tool_calls) },
+						annotations: None,
+					},
+					logprobs: None,
+				}
+			})
+			.collect();
+
+		Ok(Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id,
+				choices,
+				created: context.created,
+				model: self.model_version.clone().unwrap_or(context.model),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: self.usage_metadata.as_ref().map(usage).unwrap_or(
+					OpenAIChatCompletionResponseUsage {
+						completion_tokens: 0,
+						prompt_tokens: 0,
+						total_tokens: 0,
+						prompt_tokens_details: None,
+					},
+				),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				thinking: if thinking.is_empty() { None } else { Some(thinking.concat()) },
+				prompt_feedback: self.prompt_feedback.clone(),
+			},
+		})
+	}
+}
+
+pub struct TransformationLoss {
+	/// The thoughts of thinking models, when they are included with `includeThoughts`.
+	pub thinking: Option<String>,
+	/// The safety ratings of the prompt, which OpenAI has no counterpart for.
+	pub prompt_feedback: Option<serde_json::Value>,
+}
+
+/// Gemini doesn't always return an id, and never the creation time, so both have to be provided
+/// by the caller, along with the requested model.
+pub struct TransformationContext {
+	pub id: String,
+	pub created: u64,
+	pub model: String,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Response
+impl GeminiGenerateContentResponse {
+	/// Transforms one event of a streamed `streamGenerateContent` response. The context has to be
+	/// shared by every event of the same stream, as tool calls are indexed across events.
+	pub fn to_openai_v1_chunk(
+		&self,
+		context: &mut StreamTransformationContext,
+	) -> Result<OpenAIChatCompletionChunkResponse, TransformationError> {
+		blocked_prompt(self)?;
+
+		let mut finished = false;
+
+		let choices = self
+			.candidates
+			.iter()
+			.enumerate()
+			.map(|(position, candidate)| {
+				let index = candidate.index.unwrap_or(position as u64);
+				let parts = candidate_parts(candidate);
+				let role = if context.roles_sent.insert(index) {
+					Some("assistant".to_string())
+				} else {
+					None
+				};
+
+				let tool_calls: Vec<OpenAIChatCompletionChunkResponseChoiceToolCall> =
+					function_calls(&parts)
+						.into_iter()
+						.map(|call| {
+							let count = context.tool_calls.entry(index).or_insert(0);
+							let call_index = *count;
+							*count += 1;
+
+							chunk_tool_call(
+								tool_call_id(&context.id, index, call_index, &call),
+								call_index,
+								call,
+							)
+						})
+						.collect();
+
+				finished |= candidate.finish_reason.is_some();
+				let called_tools = context.tool_calls.get(&index).is_some_and(|count| *count > 0);
+
+				OpenAIChatCompletionChunkResponseChoice {
+					finish_reason: candidate
+						.finish_reason
+						.as_deref()
+						.map(|reason| finish_reason(Some(reason), called_tools)),
+					index,
+					delta: OpenAIChatCompletionChunkResponseChoiceDelta {
+						content: Some(text(&parts)).filter(|content| !content.is_empty()),
+						role,
+						tool_calls: if tool_calls.is_empty() { None } else { Some(tool_calls) },
+						annotations: None,
+					},
+					logprobs: None,
+				}
+			})
+			.collect();
+
+		Ok(OpenAIChatCompletionChunkResponse {
+			id: context.id.clone(),
+			choices,
+			created: context.created,
+			model: self.model_version.clone().unwrap_or_else(|| context.model.clone()),
+			system_fingerprint: None,
+			object: "chat.completion.chunk".to_string(),
+			// Gemini sends the running counts with every event, so only the final ones are kept.
+			usage: if finished && context.include_usage {
+				self.usage_metadata.as_ref().map(usage)
+			} else {
+				None
+			},
+			service_tier: None,
+		})
+	}
+}
+
+pub struct StreamTransformationContext {
+	pub id: String,
+	pub created: u64,
+	/// The requested model, used when the events have no `modelVersion`.
+	pub model: String,
+	/// Add the usage to the last chunk, like OpenAI does with `stream_options.include_usage`.
+	pub include_usage: bool,
+	roles_sent: HashSet<u64>,
+	tool_calls: HashMap<u64, usize>,
+}
+
+impl StreamTransformationContext {
+	pub fn new(id: String, created: u64, model: String, include_usage: bool) -> Self {
+		StreamTransformationContext {
+			id,
+			created,
+			model,
+			include_usage,
+			roles_sent: HashSet::new(),
+			tool_calls: HashMap::new(),
+		}
+	}
+}
+// endregion: --- Stream Response
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// Gemini blocked the prompt and returned no candidates. Holds the OpenAI error to return
+	/// instead, with the prompt feedback attached under the vendor field.
+	PromptBlocked { error: Box<OpenAIErrorResponse> },
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::PromptBlocked { error } => write!(f, "{}", error.error.message),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+/// Gemini reports a blocked prompt as a successful response with a `blockReason`, which is
+/// normalized into the content filter error OpenAI compatible clients expect.
+fn blocked_prompt(response: &GeminiGenerateContentResponse) -> Result<(), TransformationError> {
+	let Some(block_reason) = response
+		.prompt_feedback
+		.as_ref()
+		.and_then(|feedback| feedback.get("blockReason"))
+		.and_then(|reason| reason.as_str())
+	else {
+		return Ok(());
+	};
+
+	Err(TransformationError::PromptBlocked {
+		error: Box::new(OpenAIErrorResponse {
+			error: OpenAIErrorResponseError {
+				message: format!("The prompt was blocked with reason `{block_reason}`."),
+				r#type: "invalid_request_error".to_string(),
+				param: None,
+				code: Some("content_filter".to_string()),
+				vendor: Some(json!({
+					"gemini": {
+						"promptFeedback": response.prompt_feedback,
+					}
+				})),
+			},
+		}),
+	})
+}
+
+fn candidate_parts(candidate: &GeminiCandidate) -> Vec<GeminiPart> {
+	candidate.content.as_ref().map(|content| content.parts.clone()).unwrap_or_default()
+}
+
+fn text(parts: &[GeminiPart]) -> String {
+	parts
+		.iter()
+		.filter_map(|part| match part {
+			GeminiPart::TextPart { text, thought: None | Some(false) } => Some(text.as_str()),
+			_ => None,
+		})
+		.collect()
+}
+
+fn thoughts(parts: &[GeminiPart]) -> Vec<String> {
+	parts
+		.iter()
+		.filter_map(|part| match part {
+			GeminiPart::TextPart { text, thought: Some(true) } => Some(text.clone()),
+			_ => None,
+		})
+		.collect()
+}
+
+fn function_calls(parts: &[GeminiPart]) -> Vec<GeminiFunctionCall> {
+	parts
+		.iter()
+		.filter_map(|part| match part {
+			GeminiPart::FunctionCallPart { function_call } => Some(function_call.clone()),
+			_ => None,
+		})
+		.collect()
+}
+
+fn arguments(args: &Option<serde_json::Value>) -> String {
+	args.as_ref().map(|args| args.to_string()).unwrap_or_else(|| "{}".to_string())
+}
+
+/// Most models don't assign ids to function calls, so they are derived from the response id.
+fn tool_call_id(id: &str, index: u64, call_index: usize, call: &GeminiFunctionCall) -> String {
+	call.id.clone().unwrap_or_else(|| format!("call_{id}_{index}_{call_index}"))
+}
+
+fn chunk_tool_call(
+	id: String,
+	call_index: usize,
+	call: GeminiFunctionCall,
+) -> OpenAIChatCompletionChunkResponseChoiceToolCall {
+	OpenAIChatCompletionChunkResponseChoiceToolCall {
+		index: call_index as u64,
+		id: Some(id),
+		r#type: Some(OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType),
+		function: Some(OpenAIChatCompletionChunkResponseChoiceFunctionToolCall {
+			name: Some(call.name),
+			arguments: Some(arguments(&call.args)),
+		}),
+	}
+}
+
+fn finish_reason(finish_reason: Option<&str>, called_tools: bool) -> String {
+	match finish_reason {
+		Some("MAX_TOKENS") => "length".to_string(),
+		Some("SAFETY" | "RECITATION" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII") =>
+			"content_filter".to_string(),
+		_ if called_tools => "tool_calls".to_string(),
+		_ => "stop".to_string(),
+	}
+}
+
+fn usage(usage: &GeminiUsageMetadata) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		// OpenAI counts the reasoning tokens as completion tokens.
+		completion_tokens: usage.candidates_token_count.unwrap_or(0)
+			+ usage.thoughts_token_count.unwrap_or(0),
+		prompt_tokens: usage.prompt_token_count,
+		total_tokens: usage.total_token_count,
+		prompt_tokens_details: usage.cached_content_token_count.map(|cached_tokens| {
+			OpenAIChatCompletionResponseUsagePromptTokensDetails {
+				cached_tokens: Some(cached_tokens),
+			}
+		}),
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: GeminiGenerateContentResponse = serde_json::from_value(json!({
+		  "candidates": [
+			{
+			  "content": {
+				"role": "model",
+				"parts": [
+				  { "text": "The user wants the weather.", "thought": true },
+				  { "functionCall": { "name": "get_weather", "args": { "location": "Boston" } } }
+				]
+			  },
+			  "finishReason": "STOP",
+			  "index": 0
+			}
+		  ],
+		  "usageMetadata": { "promptTokenCount": 70, "candidatesTokenCount": 5, "thoughtsTokenCount": 20, "cachedContentTokenCount": 64, "totalTokenCount": 95 },
+		  "modelVersion": "gemini-2.5-flash",
+		  "responseId": "mH1aaLKzK4yqz7IP"
+		}))?;
+
+		let data = fx_response.to_openai_v1(TransformationContext {
+			id: "chatcmpl-123".to_string(),
+			created: 1700000000,
+			model: "gemini-2.5-flash".to_string(),
+		})?;
+
+		assert_eq!(data.response.id, "mH1aaLKzK4yqz7IP");
+		assert_eq!(data.response.choices[0].finish_reason, "tool_calls");
+		assert_eq!(data.response.choices[0].message.content, None);
+		assert_eq!(
+			data.response.choices[0].message.tool_calls,
+			Some(vec![OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+				id: "call_mH1aaLKzK4yqz7IP_0_0".to_string(),
+				function: OpenAIChatCompletionResponseChoiceFunctionToolCall {
+					name: "get_weather".to_string(),
+					arguments: "{\"location\":\"Boston\"}".to_string()
+				},
+			}])
+		);
+		assert_eq!(data.response.usage.completion_tokens, 25);
+		assert_eq!(
+			data.response.usage.prompt_tokens_details,
+			Some(OpenAIChatCompletionResponseUsagePromptTokensDetails { cached_tokens: Some(64) })
+		);
+
+		// Check if the thoughts were passed to the loss object.
+		assert_eq!(data.loss.thinking, Some("The user wants the weather.".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stream_response_transform_ok() -> Result<()> {
+		let fx_events: Vec<GeminiGenerateContentResponse> = serde_json::from_value(json!([
+		  {
+			"candidates": [{ "content": { "role": "model", "parts": [{ "text": "The" }] }, "index": 0 }],
+			"usageMetadata": { "promptTokenCount": 4, "candidatesTokenCount": 1, "totalTokenCount": 5 }
+		  },
+		  {
+			"candidates": [{ "content": { "role": "model", "parts": [{ "text": " sky" }] }, "finishReason": "MAX_TOKENS", "index": 0 }],
+			"usageMetadata": { "promptTokenCount": 4, "candidatesTokenCount": 2, "totalTokenCount": 6 }
+		  }
+		]))?;
+
+		let mut context = StreamTransformationContext::new(
+			"chatcmpl-123".to_string(),
+			1700000000,
+			"gemini-2.0-flash".to_string(),
+			true,
+		);
+		let data = fx_events
+			.iter()
+			.map(|event| event.to_openai_v1_chunk(&mut context))
+			.collect::<core::result::Result<Vec<_>, _>>()?;
+
+		assert_eq!(data[0].choices[0].delta.role, Some("assistant".to_string()));
+		assert_eq!(data[0].usage, None);
+		assert_eq!(data[1].choices[0].delta.role, None);
+		assert_eq!(data[1].choices[0].delta.content, Some(" sky".to_string()));
+		assert_eq!(data[1].choices[0].finish_reason, Some("length".to_string()));
+		assert_eq!(data[1].model, "gemini-2.0-flash");
+		assert_eq!(data[1].usage.as_ref().map(|usage| usage.total_tokens), Some(6));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_blocked_prompt_response_transform_fail() -> Result<()> {
+		let fx_response: GeminiGenerateContentResponse = serde_json::from_value(json!({
+		  "promptFeedback": {
+			"blockReason": "SAFETY",
+			"safetyRatings": [{ "category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "HIGH" }]
+		  },
+		  "usageMetadata": { "promptTokenCount": 8, "totalTokenCount": 8 },
+		  "modelVersion": "gemini-2.5-flash"
+		}))?;
+
+		let data = fx_response.to_openai_v1(TransformationContext {
+			id: "chatcmpl-123".to_string(),
+			created: 1700000000,
+			model: "gemini-2.5-flash".to_string(),
+		});
+
+		let Err(TransformationError::PromptBlocked { error }) = data else {
+			return Err("expected the blocked prompt to be transformed into an error".into());
+		};
+		assert_eq!(error.error.r#type, "invalid_request_error");
+		assert_eq!(error.error.code, Some("content_filter".to_string()));
+
+		// Check if the prompt feedback was attached under the vendor field.
+		let vendor = error.error.vendor.unwrap();
+		assert_eq!(vendor["gemini"]["promptFeedback"]["blockReason"], json!("SAFETY"));
+
+		// The same event ends a stream with the error.
+		let mut context = StreamTransformationContext::new(
+			"chatcmpl-123".to_string(),
+			1700000000,
+			"gemini-2.5-flash".to_string(),
+			true,
+		);
+		assert!(matches!(
+			fx_response.to_openai_v1_chunk(&mut context),
+			Err(TransformationError::PromptBlocked { .. })
+		));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
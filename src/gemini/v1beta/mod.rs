@@ -0,0 +1,4 @@
+pub mod embed_content;
+pub mod error;
+pub mod generate_content;
+pub mod vertex;
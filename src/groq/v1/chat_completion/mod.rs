@@ -0,0 +1 @@
+#[cfg(feature = "transformer")] pub mod transformer;
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage as OpenAIChatCompletionMessage,
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+};
+
+impl OpenAIChatCompletionRequest {
+	/// Groq serves the OpenAI API, so the request keeps its shape, but the parameters Groq rejects
+	/// with a 400 are removed.
+	pub fn to_groq_v1(&self, context: TransformationContext) -> Transformation {
+		let _ = context;
+		let mut request = self.clone();
+
+		// Groq only accepts `n` equal to 1.
+		let n = request.n.take_if(|n| *n != 1);
+		let names = request
+			.messages
+			.iter_mut()
+			.filter_map(|message| match message {
+				OpenAIChatCompletionMessage::SystemMessage { name, .. }
+				| OpenAIChatCompletionMessage::UserMessage { name, .. }
+				| OpenAIChatCompletionMessage::AssistantMessage { name, .. } => name.take(),
+				OpenAIChatCompletionMessage::ToolMessage { .. } => None,
+			})
+			.collect();
+
+		Transformation {
+			loss: TransformationLoss {
+				n,
+				logprobs: request.logprobs.take(),
+				top_logprobs: request.top_logprobs.take(),
+				logit_bias: request.logit_bias.take(),
+				frequency_penalty: request.frequency_penalty.take(),
+				presence_penalty: request.presence_penalty.take(),
+				names,
+			},
+			request,
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub n: Option<u64>,
+	pub logprobs: Option<bool>,
+	pub top_logprobs: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub frequency_penalty: Option<f64>,
+	pub presence_penalty: Option<f64>,
+	/// The participant names removed from the messages.
+	pub names: Vec<String>,
+}
+
+pub struct TransformationContext {}
+
+pub struct Transformation {
+	pub request: OpenAIChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use crate::openai::v1::chat_completion::request::UserMessageContent as OpenAIUserMessageContent;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "llama-3.3-70b-versatile",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "name": "alice", "content": "Hello!" }
+		  ],
+		  "n": 1,
+		  "temperature": 0.5,
+		  "logprobs": true,
+		  "top_logprobs": 2,
+		  "logit_bias": { "1234": -100 },
+		  "presence_penalty": 0.2
+		}))?;
+
+		let data = fx_request.to_groq_v1(TransformationContext {});
+
+		assert_eq!(data.request.n, Some(1));
+		assert_eq!(data.request.temperature, Some(0.5));
+		assert_eq!(data.request.logprobs, None);
+		assert_eq!(data.request.logit_bias, None);
+		assert_eq!(data.request.presence_penalty, None);
+		assert_eq!(
+			data.request.messages[1],
+			OpenAIChatCompletionMessage::UserMessage {
+				name: None,
+				content: OpenAIUserMessageContent::TextContent("Hello!".to_string())
+			}
+		);
+
+		// Check if the parameters Groq rejects were passed to the loss object.
+		assert_eq!(data.loss.n, None);
+		assert_eq!(data.loss.logprobs, Some(true));
+		assert_eq!(data.loss.top_logprobs, Some(2));
+		assert_eq!(data.loss.presence_penalty, Some(0.2));
+		assert_eq!(data.loss.names, vec!["alice".to_string()]);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,2 @@
+pub mod chat_completion;
+pub mod rate_limit;
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+/// The rate limit state Groq reports in the headers of every response.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct RateLimit {
+	/// `x-ratelimit-limit-requests`, the number of requests allowed per day.
+	pub limit_requests: Option<u64>,
+	/// `x-ratelimit-limit-tokens`, the number of tokens allowed per minute.
+	pub limit_tokens: Option<u64>,
+	/// `x-ratelimit-remaining-requests`
+	pub remaining_requests: Option<u64>,
+	/// `x-ratelimit-remaining-tokens`
+	pub remaining_tokens: Option<u64>,
+	/// `x-ratelimit-reset-requests`, the time until the request limit is fully restored.
+	pub reset_requests: Option<Duration>,
+	/// `x-ratelimit-reset-tokens`, the time until the token limit is fully restored.
+	pub reset_tokens: Option<Duration>,
+	/// `retry-after`, only sent with 429 responses.
+	pub retry_after: Option<Duration>,
+}
+
+impl RateLimit {
+	/// Reads the rate limit headers out of the response headers. Header names are matched case
+	/// insensitively, and headers with a value that can't be parsed are ignored.
+	pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+		let mut rate_limit = RateLimit::default();
+
+		for (name, value) in headers {
+			let value = value.trim();
+			match name.to_ascii_lowercase().as_str() {
+				"x-ratelimit-limit-requests" => rate_limit.limit_requests = value.parse().ok(),
+				"x-ratelimit-limit-tokens" => rate_limit.limit_tokens = value.parse().ok(),
+				"x-ratelimit-remaining-requests" =>
+					rate_limit.remaining_requests = value.parse().ok(),
+				"x-ratelimit-remaining-tokens" => rate_limit.remaining_tokens = value.parse().ok(),
+				"x-ratelimit-reset-requests" => rate_limit.reset_requests = parse_duration(value),
+				"x-ratelimit-reset-tokens" => rate_limit.reset_tokens = parse_duration(value),
+				"retry-after" =>
+					rate_limit.retry_after = value.parse().ok().map(Duration::from_secs),
+				_ => {},
+			}
+		}
+
+		rate_limit
+	}
+}
+
+/// Parses durations like `2m59.56s`, `7.66s` or `250ms`.
+fn parse_duration(value: &str) -> Option<Duration> {
+	let mut total = 0.0;
+	let mut rest = value;
+
+	while !rest.is_empty() {
+		let number_end = rest.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+		let number: f64 = rest[..number_end].parse().ok()?;
+		rest = &rest[number_end..];
+
+		let unit_end = rest.find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len());
+		total += number
+			* match &rest[..unit_end] {
+				"h" => 3600.0,
+				"m" => 60.0,
+				"s" => 1.0,
+				"ms" => 0.001,
+				_ => return None,
+			};
+		rest = &rest[unit_end..];
+	}
+
+	if value.is_empty() {
+		None
+	} else {
+		// The headers come from upstream, so a duration too large to represent is ignored.
+		Duration::try_from_secs_f64(total).ok()
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_rate_limit_headers_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_headers = [
+			("content-type", "application/json"),
+			("retry-after", "2"),
+			("X-RateLimit-Limit-Requests", "14400"),
+			("x-ratelimit-limit-tokens", "18000"),
+			("x-ratelimit-remaining-requests", "14370"),
+			("x-ratelimit-remaining-tokens", "17997"),
+			("x-ratelimit-reset-requests", "2m59.56s"),
+			("x-ratelimit-reset-tokens", "7.66s"),
+		];
+
+		let data = RateLimit::from_headers(fx_headers);
+
+		assert_eq!(data.limit_requests, Some(14400));
+		assert_eq!(data.remaining_tokens, Some(17997));
+		assert_eq!(data.reset_requests, Some(Duration::from_secs_f64(179.56)));
+		assert_eq!(data.reset_tokens, Some(Duration::from_secs_f64(7.66)));
+		assert_eq!(data.retry_after, Some(Duration::from_secs(2)));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rate_limit_invalid_headers_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_headers = [
+			("x-ratelimit-remaining-requests", "many"),
+			("x-ratelimit-reset-tokens", "7.66 seconds"),
+		];
+
+		let data = RateLimit::from_headers(fx_headers);
+
+		assert_eq!(data, RateLimit::default());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rate_limit_overflowing_duration_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_headers = [("x-ratelimit-reset-tokens", "99999999999999999999h")];
+
+		let data = RateLimit::from_headers(fx_headers);
+
+		assert_eq!(data.reset_tokens, None);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
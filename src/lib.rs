@@ -1,3 +1,16 @@
 pub mod openai;
 
+#[cfg(feature = "anthropic")] pub mod anthropic;
 #[cfg(feature = "azure")] pub mod azure;
+#[cfg(feature = "cohere")] pub mod cohere;
+#[cfg(feature = "databricks")] pub mod databricks;
+#[cfg(feature = "fireworks")] pub mod fireworks;
+#[cfg(feature = "gemini")] pub mod gemini;
+#[cfg(feature = "groq")] pub mod groq;
+#[cfg(feature = "mistral")] pub mod mistral;
+#[cfg(feature = "ollama")] pub mod ollama;
+#[cfg(feature = "openrouter")] pub mod openrouter;
+#[cfg(feature = "perplexity")] pub mod perplexity;
+#[cfg(feature = "together")] pub mod together;
+#[cfg(feature = "watsonx")] pub mod watsonx;
+#[cfg(feature = "xai")] pub mod xai;
@@ -0,0 +1,277 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// ID of the model to use.
+	pub model: String,
+
+	/// The prompt(s) to generate completions for, encoded as a list of dict with role and content.
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// What sampling temperature to use, between 0.0 and 1.5. Higher values like 0.8 will make the
+	/// output more random, while lower values like 0.2 will make it more focused and
+	/// deterministic. The default value varies depending on the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// default: 1
+	/// Nucleus sampling, where the model considers the results of the tokens with top_p
+	/// probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are
+	/// considered.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// The maximum number of tokens to generate in the completion. The token count of your prompt
+	/// plus max_tokens cannot exceed the model's context length.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// default: false
+	/// Whether to stream back partial progress. If set, tokens will be sent as data-only
+	/// server-side events as they become available, with the stream terminated by a data: [DONE]
+	/// message.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// Stop generation if this token is detected. Or if one of these tokens is detected when
+	/// providing an array.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<ChatCompletionStop>,
+
+	/// The seed to use for random sampling. If set, different calls will generate deterministic
+	/// results.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub random_seed: Option<i64>,
+
+	/// An object specifying the format that the model must output. Used to enable JSON mode.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// A list of tools the model may call. Currently, only functions are supported as a tool.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	/// Controls which (if any) tool is called by the model. Unlike OpenAI, forcing the model to
+	/// call a tool is done with `any`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+
+	/// default: 0
+	/// Penalizes the repetition of words or phrases, encouraging the model to use a wider variety
+	/// of words and phrases.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// default: 0
+	/// Penalizes the repetition of words based on their frequency in the generated text.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// Whether to inject a safety prompt before all conversations.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub safe_prompt: Option<bool>,
+}
+
+// region:    --- ChatCompletionStop
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum ChatCompletionStop {
+	StringStop(String),
+	ArrayStop(Vec<String>),
+}
+// endregion: --- ChatCompletionStop
+
+// region:    --- ChatCompletionMessage
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "role"))]
+pub enum ChatCompletionMessage {
+	#[cfg_attr(feature = "serde", serde(rename = "system", alias = "system"))]
+	SystemMessage { content: String },
+	#[cfg_attr(feature = "serde", serde(rename = "user", alias = "user"))]
+	UserMessage { content: UserMessageContent },
+	#[cfg_attr(feature = "serde", serde(rename = "assistant", alias = "assistant"))]
+	AssistantMessage {
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		content: Option<String>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		tool_calls: Option<Vec<AssistantToolCall>>,
+		/// Set to true to have the model continue this message instead of answering it.
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		prefix: Option<bool>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "tool", alias = "tool"))]
+	ToolMessage {
+		content: String,
+		tool_call_id: String,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		name: Option<String>,
+	},
+}
+
+// region:    --- Chat Completion Message Content
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum UserMessageContent {
+	TextContent(String),
+	ArrayContentParts(Vec<UserMessageContentPart>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum UserMessageContentPart {
+	#[cfg_attr(feature = "serde", serde(rename = "text", alias = "text"))]
+	TextContentPart { text: String },
+	#[cfg_attr(feature = "serde", serde(rename = "image_url", alias = "image_url"))]
+	ImageContentPart { image_url: String },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssistantToolCall {
+	pub id: String,
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: AssistantToolCallType,
+	pub function: AssistantToolCallFunction,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssistantToolCallType {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssistantToolCallFunction {
+	pub name: String,
+	pub arguments: String,
+}
+// endregion  --- Chat Completion Message Content
+// endregion  --- ChatCompletionMessage
+
+// region:    --- Tools
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatCompletionTool {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionTool { function: ChatCompletionToolFunction },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionToolFunction {
+	pub name: String,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub description: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub parameters: Option<serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum ChatCompletionToolChoice {
+	StringChoice(ChatCompletionToolChoiceMode),
+	FunctionChoice(ChatCompletionToolChoiceObject),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatCompletionToolChoiceMode {
+	#[cfg_attr(feature = "serde", serde(rename = "auto", alias = "auto"))]
+	Auto,
+	#[cfg_attr(feature = "serde", serde(rename = "none", alias = "none"))]
+	None,
+	#[cfg_attr(feature = "serde", serde(rename = "any", alias = "any"))]
+	Any,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatCompletionToolChoiceObject {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionTool { function: ChatCompletionToolChoiceFunction },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionToolChoiceFunction {
+	pub name: String,
+}
+// endregion: --- Tools
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_mistral_request_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "mistral-large-latest",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "What's the weather in Paris?" },
+			{ "role": "assistant", "content": "", "tool_calls": [{ "id": "D681PevKs", "type": "function", "function": { "name": "get_weather", "arguments": "{\"location\": \"Paris\"}" } }] },
+			{ "role": "tool", "content": "15 degrees", "tool_call_id": "D681PevKs", "name": "get_weather" }
+		  ],
+		  "random_seed": 42,
+		  "tool_choice": "any",
+		  "safe_prompt": true
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.messages.len(), 4);
+		assert_eq!(
+			data.tool_choice,
+			Some(ChatCompletionToolChoice::StringChoice(ChatCompletionToolChoiceMode::Any))
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_mistral_request_02_encode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = ChatCompletionRequest {
+			model: "mistral-small-latest".to_string(),
+			messages: vec![ChatCompletionMessage::UserMessage {
+				content: UserMessageContent::TextContent("Hello!".to_string()),
+			}],
+			temperature: None,
+			top_p: None,
+			max_tokens: None,
+			stream: None,
+			stop: None,
+			random_seed: None,
+			response_format: None,
+			tools: None,
+			tool_choice: Some(ChatCompletionToolChoice::StringChoice(
+				ChatCompletionToolChoiceMode::None,
+			)),
+			presence_penalty: None,
+			frequency_penalty: None,
+			safe_prompt: None,
+		};
+
+		let data = serde_json::to_value(&fx_request)?;
+
+		assert_eq!(
+			data,
+			json!({
+			  "model": "mistral-small-latest",
+			  "messages": [{ "role": "user", "content": "Hello!" }],
+			  "tool_choice": "none"
+			})
+		);
+
+		Ok(())
+	}
+}
+// endregion  --- Tests
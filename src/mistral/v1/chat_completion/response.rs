@@ -0,0 +1,190 @@
+// region:    --- Object Response
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponse {
+	/// A unique identifier for the chat completion.
+	pub id: String,
+	/// The object type, which is always chat.completion.
+	pub object: String,
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: u64,
+	/// The model used for the chat completion.
+	pub model: String,
+	/// A list of chat completion choices.
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+	/// Usage statistics for the completion request.
+	pub usage: ChatCompletionResponseUsage,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponseChoice {
+	/// The index of the choice in the list of choices.
+	pub index: u64,
+	/// A chat completion message generated by the model.
+	pub message: ChatCompletionObjectResponseChoiceMessage,
+	/// The reason the model stopped generating tokens. One of stop, length, model_length, error
+	/// or tool_calls.
+	pub finish_reason: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponseChoiceMessage {
+	/// The role of the author of the message
+	pub role: String,
+	/// The contents of the message.
+	pub content: Option<String>,
+	/// The tool calls generated by the model, such as function calls.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<Vec<ChatCompletionResponseToolCall>>,
+}
+// endregion: --- Object Response
+
+// region:    --- Chunk Response
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	/// A unique identifier for the chat completion. Each chunk has the same ID.
+	pub id: String,
+	/// The object type, which is always chat.completion.chunk.
+	pub object: String,
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: u64,
+	/// The model to generate the completion.
+	pub model: String,
+	/// A list of chat completion choices.
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+	/// Usage statistics, only sent with the last chunk.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoice {
+	/// The index of the choice in the list of choices.
+	pub index: u64,
+	/// A chat completion delta generated by streamed model responses.
+	pub delta: ChatCompletionChunkResponseChoiceDelta,
+	/// The reason the model stopped generating tokens.
+	pub finish_reason: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoiceDelta {
+	/// The role of the author of this message.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub role: Option<String>,
+	/// The contents of the chunk message.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub content: Option<String>,
+	/// Mistral sends complete tool calls in a single chunk instead of streaming the arguments.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<Vec<ChatCompletionResponseToolCall>>,
+}
+// endregion: --- Chunk Response
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseToolCall {
+	/// The ID of the tool call.
+	pub id: String,
+	/// The type of the tool. Not always sent by Mistral.
+	#[cfg_attr(feature = "serde", serde(rename = "type", skip_serializing_if = "Option::is_none"))]
+	pub r#type: Option<ChatCompletionResponseToolCallType>,
+	/// The function that the model called.
+	pub function: ChatCompletionResponseFunctionToolCall,
+	/// The index of the tool call, only sent in chunks.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub index: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatCompletionResponseToolCallType {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseFunctionToolCall {
+	/// The name of the function to call.
+	pub name: String,
+	/// The arguments to call the function with, as generated by the model in JSON format.
+	pub arguments: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsage {
+	/// Number of tokens in the prompt.
+	pub prompt_tokens: u64,
+	/// Number of tokens in the generated completion.
+	pub completion_tokens: u64,
+	/// Total number of tokens used in the request (prompt + completion).
+	pub total_tokens: u64,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_object_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "cmpl-e5cc70bb28c444948073e77776eb30ef",
+		  "object": "chat.completion",
+		  "created": 1702256327,
+		  "model": "mistral-large-latest",
+		  "choices": [
+			{
+			  "index": 0,
+			  "message": {
+				"role": "assistant",
+				"content": "",
+				"tool_calls": [{ "id": "D681PevKs", "function": { "name": "get_weather", "arguments": "{\"location\": \"Paris\"}" } }]
+			  },
+			  "finish_reason": "tool_calls"
+			}
+		  ],
+		  "usage": { "prompt_tokens": 16, "completion_tokens": 34, "total_tokens": 50 }
+		})
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].message.tool_calls.as_ref().unwrap()[0].r#type, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_response_chunk_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "cmpl-e5cc70bb28c444948073e77776eb30ef",
+		  "object": "chat.completion.chunk",
+		  "created": 1702256327,
+		  "model": "mistral-large-latest",
+		  "choices": [{ "index": 0, "delta": { "content": "Hello" }, "finish_reason": null }]
+		})
+		.to_string();
+
+		let data: ChatCompletionChunkResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].delta.content, Some("Hello".to_string()));
+		assert_eq!(data.usage, None);
+
+		Ok(())
+	}
+}
+// endregion: --- Tests
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	AssistantToolCallType as OpenAIAssistantToolCallType,
+	ChatCompletionMessage as OpenAIChatCompletionMessage,
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop, ChatCompletionTool as OpenAIChatCompletionTool,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+	ChatCompletionToolChoiceObject as OpenAIChatCompletionToolChoiceObject,
+	UserMessageContent as OpenAIUserMessageContent,
+	UserMessageContentPart as OpenAIUserMessageContentPart,
+};
+
+use crate::mistral::v1::chat_completion::request::{
+	AssistantToolCall as MistralAssistantToolCall,
+	AssistantToolCallFunction as MistralAssistantToolCallFunction,
+	AssistantToolCallType as MistralAssistantToolCallType,
+	ChatCompletionMessage as MistralChatCompletionMessage,
+	ChatCompletionRequest as MistralChatCompletionRequest,
+	ChatCompletionStop as MistralChatCompletionStop,
+	ChatCompletionTool as MistralChatCompletionTool,
+	ChatCompletionToolChoice as MistralChatCompletionToolChoice,
+	ChatCompletionToolChoiceFunction as MistralChatCompletionToolChoiceFunction,
+	ChatCompletionToolChoiceMode as MistralChatCompletionToolChoiceMode,
+	ChatCompletionToolChoiceObject as MistralChatCompletionToolChoiceObject,
+	ChatCompletionToolFunction as MistralChatCompletionToolFunction,
+	UserMessageContent as MistralUserMessageContent,
+	UserMessageContentPart as MistralUserMessageContentPart,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_mistral_v1(&self, context: TransformationContext) -> Transformation {
+		let (tool_choice, tool_choice_loss) = match self.tool_choice.clone() {
+			None => (None, None),
+			Some(OpenAIChatCompletionToolChoice::StringChoice(value)) => {
+				let mode = match value.as_str() {
+					"auto" => Some(MistralChatCompletionToolChoiceMode::Auto),
+					"none" => Some(MistralChatCompletionToolChoiceMode::None),
+					// Mistral uses `any` to force the model to call a tool.
+					"required" => Some(MistralChatCompletionToolChoiceMode::Any),
+					_ => None,
+				};
+				match mode {
+					Some(mode) => (Some(MistralChatCompletionToolChoice::StringChoice(mode)), None),
+					None => (None, Some(OpenAIChatCompletionToolChoice::StringChoice(value))),
+				}
+			},
+			Some(OpenAIChatCompletionToolChoice::FunctionChoice(
+				OpenAIChatCompletionToolChoiceObject::FunctionTool { function },
+			)) => (
+				Some(MistralChatCompletionToolChoice::FunctionChoice(
+					MistralChatCompletionToolChoiceObject::FunctionTool {
+						function: MistralChatCompletionToolChoiceFunction { name: function.name },
+					},
+				)),
+				None,
+			),
+		};
+
+		Transformation {
+			request: MistralChatCompletionRequest {
+				model: self.model.clone(),
+				messages: self
+					.messages
+					.clone()
+					.into_iter()
+					.map(|message| match message {
+						OpenAIChatCompletionMessage::SystemMessage { content, .. } => MistralChatCompletionMessage::SystemMessage { content },
+						OpenAIChatCompletionMessage::UserMessage { content, .. } => MistralChatCompletionMessage::UserMessage {
+							content: match content {
+								OpenAIUserMessageContent::TextContent(value) => MistralUserMessageContent::TextContent(value),
+								OpenAIUserMessageContent::ArrayContentParts(parts) => MistralUserMessageContent::ArrayContentParts(
+									parts
+										.into_iter()
+										.map(|part| match part {
+											OpenAIUserMessageContentPart::TextContentPart { text } => MistralUserMessageContentPart::TextContentPart { text },
+											OpenAIUserMessageContentPart::ImageContentPart { image_url } => MistralUserMessageContentPart::ImageContentPart { image_url: image_url.url },
+										})
+										.collect(),
+								),
+							},
+						},
+						OpenAIChatCompletionMessage::AssistantMessage { content, tool_calls, .. } => MistralChatCompletionMessage::AssistantMessage {
+							content,
+							tool_calls: tool_calls.map(|calls| {
+								calls
+									.into_iter()
+									.map(|call| MistralAssistantToolCall {
+										id: mistral_tool_call_id(&call.id),
+										r#type: match call.r#type {
+											OpenAIAssistantToolCallType::FunctionType => MistralAssistantToolCallType::FunctionType,
+										},
+										function: MistralAssistantToolCallFunction { name: call.function.name, arguments: call.function.arguments },
+									})
+									.collect()
+							}),
+							prefix: None,
+						},
+						OpenAIChatCompletionMessage::ToolMessage { content, tool_call_id } => MistralChatCompletionMessage::ToolMessage { content, tool_call_id: mistral_tool_call_id(&tool_call_id), name: None },
+					})
+					.collect(),
+				temperature: self.temperature,
+				top_p: self.top_p,
+				max_tokens: self.max_tokens,
+				stream: self.stream,
+				stop: self.stop.clone().map(|stop| match stop {
+					OpenAIChatCompletionStop::StringStop(v) => MistralChatCompletionStop::StringStop(v),
+					OpenAIChatCompletionStop::ArrayStop(v) => MistralChatCompletionStop::ArrayStop(v),
+				}),
+				random_seed: self.seed,
+				response_format: self.response_format.clone(),
+				tools: self.tools.clone().map(|tls| {
+					tls.into_iter()
+						.map(|tool| match tool {
+							OpenAIChatCompletionTool::FunctionTool { function } => MistralChatCompletionTool::FunctionTool { function: MistralChatCompletionToolFunction { name: function.name, description: function.description, parameters: function.parameters } },
+						})
+						.collect()
+				}),
+				tool_choice,
+				presence_penalty: self.presence_penalty,
+				frequency_penalty: self.frequency_penalty,
+				safe_prompt: context.safe_prompt,
+			},
+			loss: TransformationLoss {
+				n: self.n,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				logit_bias: self.logit_bias.clone(),
+				user: self.user.clone(),
+				tool_choice: tool_choice_loss,
+			},
+		}
+	}
+}
+
+/// Mistral only accepts tool call ids of 9 alphanumeric characters, so other ids (like OpenAI's
+/// `call_...`) are replaced by a hash of the id. The same id always gives the same replacement,
+/// so tool results still match their tool call.
+fn mistral_tool_call_id(id: &str) -> String {
+	const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+	if id.len() == 9 && id.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+		return id.to_string();
+	}
+
+	// FNV-1a, as the hashers of the standard library are not guaranteed to be stable.
+	let mut hash: u64 = 0xcbf29ce484222325;
+	for byte in id.bytes() {
+		hash = (hash ^ byte as u64).wrapping_mul(0x100000001b3);
+	}
+
+	(0..9)
+		.map(|_| {
+			let index = (hash % ALPHABET.len() as u64) as usize;
+			hash /= ALPHABET.len() as u64;
+			ALPHABET[index] as char
+		})
+		.collect()
+}
+
+pub struct TransformationLoss {
+	/// Mistral always generates a single choice.
+	pub n: Option<u64>,
+	pub logprobs: Option<bool>,
+	pub top_logprobs: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub user: Option<String>,
+	/// Set when the tool choice has no Mistral equivalent.
+	pub tool_choice: Option<OpenAIChatCompletionToolChoice>,
+}
+
+pub struct TransformationContext {
+	pub safe_prompt: Option<bool>,
+}
+
+pub struct Transformation {
+	pub request: MistralChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "mistral-large-latest",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "Hello!" }
+		  ],
+		  "n": 3,
+		  "seed": 42,
+		  "logprobs": true,
+		  "user": "user-1234"
+		}))?;
+
+		let data = fx_request.to_mistral_v1(TransformationContext { safe_prompt: Some(true) });
+
+		assert_eq!(data.request.model, "mistral-large-latest");
+		assert_eq!(data.request.random_seed, Some(42));
+		assert_eq!(data.request.safe_prompt, Some(true));
+
+		// Check if the parameters Mistral doesn't support were passed to the loss object.
+		assert_eq!(data.loss.n, Some(3));
+		assert_eq!(data.loss.logprobs, Some(true));
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_choice_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "mistral-large-latest",
+		  "messages": [{ "role": "user", "content": "What's the weather in Paris?" }],
+		  "tools": [{ "type": "function", "function": { "name": "get_weather" } }],
+		  "tool_choice": "required"
+		}))?;
+
+		let data = fx_request.to_mistral_v1(TransformationContext { safe_prompt: None });
+
+		assert_eq!(
+			data.request.tool_choice,
+			Some(MistralChatCompletionToolChoice::StringChoice(
+				MistralChatCompletionToolChoiceMode::Any
+			))
+		);
+		assert_eq!(data.loss.tool_choice, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_call_ids_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "mistral-large-latest",
+		  "messages": [
+			{ "role": "user", "content": "What's the weather in Paris?" },
+			{
+			  "role": "assistant",
+			  "tool_calls": [
+				{ "id": "call_abc123_0", "type": "function", "function": { "name": "get_weather", "arguments": "{}" } },
+				{ "id": "D681PevKs", "type": "function", "function": { "name": "get_weather", "arguments": "{}" } }
+			  ]
+			},
+			{ "role": "tool", "content": "22 degrees", "tool_call_id": "call_abc123_0" }
+		  ]
+		}))?;
+
+		let data = fx_request.to_mistral_v1(TransformationContext { safe_prompt: None });
+
+		let MistralChatCompletionMessage::AssistantMessage { tool_calls: Some(calls), .. } =
+			&data.request.messages[1]
+		else {
+			return Err("expected an assistant message with tool calls".into());
+		};
+		let MistralChatCompletionMessage::ToolMessage { tool_call_id, .. } =
+			&data.request.messages[2]
+		else {
+			return Err("expected a tool message".into());
+		};
+
+		// Check if the OpenAI id was replaced by a valid Mistral id, the same for the call and its
+		// result.
+		assert_eq!(calls[0].id.len(), 9);
+		assert!(calls[0].id.bytes().all(|byte| byte.is_ascii_alphanumeric()));
+		assert_eq!(*tool_call_id, calls[0].id);
+		assert_eq!(calls[1].id, "D681PevKs");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_unknown_tool_choice_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "mistral-large-latest",
+		  "messages": [{ "role": "user", "content": "What's the weather in Paris?" }],
+		  "tool_choice": "sometimes"
+		}))?;
+
+		let data = fx_request.to_mistral_v1(TransformationContext { safe_prompt: None });
+
+		assert_eq!(data.request.tool_choice, None);
+
+		// Check if the tool choice was passed to the loss object.
+		assert_eq!(
+			data.loss.tool_choice,
+			Some(OpenAIChatCompletionToolChoice::StringChoice("sometimes".to_string()))
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
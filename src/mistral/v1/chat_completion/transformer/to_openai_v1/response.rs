@@ -0,0 +1,205 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as OpenAIChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceDelta as OpenAIChatCompletionChunkResponseChoiceDelta,
+	ChatCompletionChunkResponseChoiceFunctionToolCall as OpenAIChatCompletionChunkResponseChoiceFunctionToolCall,
+	ChatCompletionChunkResponseChoiceToolCall as OpenAIChatCompletionChunkResponseChoiceToolCall,
+	ChatCompletionChunkResponseChoiceToolCallType as OpenAIChatCompletionChunkResponseChoiceToolCallType,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionObjectResponseChoice as OpenAIChatCompletionObjectResponseChoice,
+	ChatCompletionObjectResponseChoiceMessage as OpenAIChatCompletionObjectResponseChoiceMessage,
+	ChatCompletionObjectResponseChoiceToolCall as OpenAIChatCompletionObjectResponseChoiceToolCall,
+	ChatCompletionResponseChoiceFunctionToolCall as OpenAIChatCompletionResponseChoiceFunctionToolCall,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+};
+
+use crate::mistral::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as MistralChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as MistralChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as MistralChatCompletionResponseUsage,
+};
+
+impl MistralChatCompletionObjectResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: self
+					.choices
+					.clone()
+					.into_iter()
+					.map(|choice| OpenAIChatCompletionObjectResponseChoice {
+						finish_reason: finish_reason(choice.finish_reason),
+						index: choice.index,
+						message: OpenAIChatCompletionObjectResponseChoiceMessage {
+							content: choice.message.content,
+							role: choice.message.role,
+							tool_calls: choice.message.tool_calls.map(|calls| {
+								calls
+									.into_iter()
+									.map(|call| OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+										id: call.id,
+										function: OpenAIChatCompletionResponseChoiceFunctionToolCall { name: call.function.name, arguments: call.function.arguments },
+									})
+									.collect()
+							}),
+							annotations: None,
+						},
+						logprobs: None,
+					})
+					.collect(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: self.object.clone(),
+				usage: usage(self.usage.clone()),
+				service_tier: None,
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+impl MistralChatCompletionChunkResponse {
+	pub fn to_openai_v1(&self) -> ChunkTransformation {
+		ChunkTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices: self
+					.choices
+					.clone()
+					.into_iter()
+					.map(|choice| OpenAIChatCompletionChunkResponseChoice {
+						finish_reason: choice.finish_reason.map(finish_reason),
+						index: choice.index,
+						delta: OpenAIChatCompletionChunkResponseChoiceDelta {
+							content: choice.delta.content,
+							role: choice.delta.role,
+							tool_calls: choice.delta.tool_calls.map(|calls| {
+								calls
+									.into_iter()
+									.enumerate()
+									// Mistral sends every tool call in full, so each one maps to a
+									// first OpenAI delta carrying the id, name and all arguments.
+									.map(|(position, call)| OpenAIChatCompletionChunkResponseChoiceToolCall {
+										index: call.index.unwrap_or(position as u64),
+										id: Some(call.id),
+										r#type: Some(OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType),
+										function: Some(OpenAIChatCompletionChunkResponseChoiceFunctionToolCall { name: Some(call.function.name), arguments: Some(call.function.arguments) }),
+									})
+									.collect()
+							}),
+							annotations: None,
+						},
+						logprobs: None,
+					})
+					.collect(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: self.object.clone(),
+				usage: self.usage.clone().map(usage),
+				service_tier: None,
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+fn finish_reason(finish_reason: String) -> String {
+	match finish_reason.as_str() {
+		// The prompt plus the completion reached the context length of the model.
+		"model_length" => "length".to_string(),
+		_ => finish_reason,
+	}
+}
+
+fn usage(usage: MistralChatCompletionResponseUsage) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: usage.completion_tokens,
+		prompt_tokens: usage.prompt_tokens,
+		total_tokens: usage.total_tokens,
+		prompt_tokens_details: None,
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+
+pub struct ChunkTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: MistralChatCompletionObjectResponse = serde_json::from_value(json!({
+		  "id": "cmpl-e5cc70bb28c444948073e77776eb30ef",
+		  "object": "chat.completion",
+		  "created": 1702256327,
+		  "model": "mistral-large-latest",
+		  "choices": [{ "index": 0, "message": { "role": "assistant", "content": "Hello" }, "finish_reason": "model_length" }],
+		  "usage": { "prompt_tokens": 16, "completion_tokens": 34, "total_tokens": 50 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.choices[0].message.content, Some("Hello".to_string()));
+		assert_eq!(data.response.choices[0].finish_reason, "length");
+		assert_eq!(data.response.usage.total_tokens, 50);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_call_chunk_transform_ok() -> Result<()> {
+		let fx_response: MistralChatCompletionChunkResponse = serde_json::from_value(json!({
+		  "id": "cmpl-e5cc70bb28c444948073e77776eb30ef",
+		  "object": "chat.completion.chunk",
+		  "created": 1702256327,
+		  "model": "mistral-large-latest",
+		  "choices": [
+			{
+			  "index": 0,
+			  "delta": {
+				"tool_calls": [
+				  { "id": "D681PevKs", "function": { "name": "get_weather", "arguments": "{\"location\": \"Paris\"}" } },
+				  { "id": "xJ2kQ9dLm", "function": { "name": "get_weather", "arguments": "{\"location\": \"Rome\"}" } }
+				]
+			  },
+			  "finish_reason": "tool_calls"
+			}
+		  ],
+		  "usage": { "prompt_tokens": 16, "completion_tokens": 34, "total_tokens": 50 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		let tool_calls = data.response.choices[0].delta.tool_calls.clone().unwrap();
+		assert_eq!(tool_calls[1].index, 1);
+		assert_eq!(tool_calls[1].id, Some("xJ2kQ9dLm".to_string()));
+		assert_eq!(
+			tool_calls[1].r#type,
+			Some(OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType)
+		);
+		assert_eq!(data.response.usage.map(|usage| usage.prompt_tokens), Some(16));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
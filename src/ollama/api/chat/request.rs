@@ -0,0 +1,176 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatRequest {
+	/// The model name, as pulled on the Ollama server (e.g. `llama3.2` or `qwen2.5:7b`).
+	pub model: String,
+
+	/// The messages of the chat, this can be used to keep a chat memory.
+	pub messages: Vec<ChatMessage>,
+
+	/// List of tools for the model to use if supported.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatTool>>,
+
+	/// The format to return a response in. Format can be `json` or a JSON schema.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub format: Option<serde_json::Value>,
+
+	/// Additional model parameters listed in the documentation for the Modelfile such as
+	/// temperature.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub options: Option<ChatOptions>,
+
+	/// default: true
+	/// If false the response will be returned as a single response object, rather than a stream
+	/// of objects.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// default: 5m
+	/// Controls how long the model will stay loaded into memory following the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub keep_alive: Option<serde_json::Value>,
+}
+
+// region:    --- ChatMessage
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatMessage {
+	/// The role of the message, either `system`, `user`, `assistant`, or `tool`.
+	pub role: String,
+
+	/// The content of the message.
+	pub content: String,
+
+	/// A list of base64-encoded images to include in the message (for multimodal models).
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub images: Option<Vec<String>>,
+
+	/// A list of tools in JSON that the model wants to use.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<Vec<ChatToolCall>>,
+
+	/// The name of the tool that was executed, for messages with the `tool` role.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatToolCall {
+	pub function: ChatToolCallFunction,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatToolCallFunction {
+	pub name: String,
+	/// Unlike OpenAI, the arguments are a JSON object instead of a JSON encoded string.
+	pub arguments: serde_json::Value,
+}
+// endregion: --- ChatMessage
+
+// region:    --- ChatOptions
+#[derive(Debug, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatOptions {
+	/// default: 0.8
+	/// The temperature of the model. Increasing the temperature will make the model answer more
+	/// creatively.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// default: 0.9
+	/// Works together with top-k. A higher value (e.g., 0.95) will lead to more diverse text,
+	/// while a lower value (e.g., 0.5) will generate more focused and conservative text.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// default: 40
+	/// Reduces the probability of generating nonsense. A higher value (e.g. 100) will give more
+	/// diverse answers, while a lower value (e.g. 10) will be more conservative.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// default: 0
+	/// Sets the random number seed to use for generation.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	/// default: -1
+	/// Maximum number of tokens to predict when generating text. (-1 = infinite generation)
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub num_predict: Option<i64>,
+
+	/// default: 2048
+	/// Sets the size of the context window used to generate the next token.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub num_ctx: Option<u64>,
+
+	/// Sets the stop sequences to use. When this pattern is encountered the LLM will stop
+	/// generating text and return.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<Vec<String>>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+}
+// endregion: --- ChatOptions
+
+// region:    --- Tools
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatTool {
+	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
+	FunctionTool { function: ChatToolFunction },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatToolFunction {
+	pub name: String,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub description: Option<String>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub parameters: Option<serde_json::Value>,
+}
+// endregion: --- Tools
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_chat_request_ollama_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "llama3.2",
+		  "messages": [
+			{ "role": "user", "content": "What is the weather today in Paris?" },
+			{ "role": "assistant", "content": "", "tool_calls": [{ "function": { "name": "get_current_weather", "arguments": { "location": "Paris, FR" } } }] },
+			{ "role": "tool", "content": "22 degrees", "tool_name": "get_current_weather" }
+		  ],
+		  "options": { "seed": 101, "temperature": 0 },
+		  "stream": false,
+		  "keep_alive": "10m"
+		})
+		.to_string();
+
+		let data: ChatRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.messages.len(), 3);
+		assert_eq!(data.options.unwrap().seed, Some(101));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
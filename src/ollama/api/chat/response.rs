@@ -0,0 +1,119 @@
+use crate::ollama::api::chat::request::ChatToolCall;
+
+/// The response of `/api/chat`. When streaming, every line of the response is one of these
+/// objects, with `done` set on the last one, which also carries the statistics.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponse {
+	/// The model name.
+	pub model: String,
+
+	/// The creation time, in RFC 3339 format.
+	pub created_at: String,
+
+	/// The generated message, or the generated part of it when streaming.
+	pub message: ChatResponseMessage,
+
+	/// Whether this is the last response of the stream.
+	pub done: bool,
+
+	/// Why the generation stopped, e.g. `stop`, `length` or `load`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub done_reason: Option<String>,
+
+	/// Time spent generating the response, in nanoseconds.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub total_duration: Option<u64>,
+
+	/// Time spent in nanoseconds loading the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub load_duration: Option<u64>,
+
+	/// Number of tokens in the prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_eval_count: Option<u64>,
+
+	/// Time spent in nanoseconds evaluating the prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_eval_duration: Option<u64>,
+
+	/// Number of tokens in the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub eval_count: Option<u64>,
+
+	/// Time in nanoseconds spent generating the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub eval_duration: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatResponseMessage {
+	/// The role of the author of the message, which is always assistant.
+	pub role: String,
+	/// The contents of the message.
+	pub content: String,
+	/// The thinking of the model, for thinking models.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub thinking: Option<String>,
+	/// The tool calls generated by the model. Each tool call is always sent in full.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_calls: Option<Vec<ChatToolCall>>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_chat_response_ollama_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "model": "llama3.2",
+		  "created_at": "2023-12-12T14:13:43.416799Z",
+		  "message": { "role": "assistant", "content": "Hello! How are you today?" },
+		  "done": true,
+		  "done_reason": "stop",
+		  "total_duration": 5191566416u64,
+		  "load_duration": 2154458,
+		  "prompt_eval_count": 26,
+		  "prompt_eval_duration": 383809000,
+		  "eval_count": 298,
+		  "eval_duration": 4799921000u64
+		})
+		.to_string();
+
+		let data: ChatResponse = serde_json::from_str(&fx_response)?;
+
+		assert!(data.done);
+		assert_eq!(data.eval_count, Some(298));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_chat_response_ollama_stream_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "model": "llama3.2",
+		  "created_at": "2023-08-04T08:52:19.385406455-07:00",
+		  "message": { "role": "assistant", "content": "The" },
+		  "done": false
+		})
+		.to_string();
+
+		let data: ChatResponse = serde_json::from_str(&fx_response)?;
+
+		assert!(!data.done);
+		assert_eq!(data.prompt_eval_count, None);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage as OpenAIChatCompletionMessage,
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop, ChatCompletionTool as OpenAIChatCompletionTool,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+	UserMessageContent as OpenAIUserMessageContent,
+	UserMessageContentPart as OpenAIUserMessageContentPart,
+};
+
+use crate::ollama::api::chat::request::{
+	ChatMessage as OllamaChatMessage, ChatOptions as OllamaChatOptions,
+	ChatRequest as OllamaChatRequest, ChatTool as OllamaChatTool,
+	ChatToolCall as OllamaChatToolCall, ChatToolCallFunction as OllamaChatToolCallFunction,
+	ChatToolFunction as OllamaChatToolFunction,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_ollama_api(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		let mut image_urls = Vec::<String>::new();
+		// Ollama identifies tool results by the tool name instead of the tool call id.
+		let mut tool_names = HashMap::<String, String>::new();
+		let mut messages = Vec::<OllamaChatMessage>::new();
+
+		for message in self.messages.clone() {
+			messages.push(match message {
+				OpenAIChatCompletionMessage::SystemMessage { content, .. } =>
+					message_with_content("system", content),
+				OpenAIChatCompletionMessage::UserMessage { content, .. } => match content {
+					OpenAIUserMessageContent::TextContent(text) =>
+						message_with_content("user", text),
+					OpenAIUserMessageContent::ArrayContentParts(parts) => {
+						let mut text = Vec::<String>::new();
+						let mut images = Vec::<String>::new();
+						for part in parts {
+							match part {
+								OpenAIUserMessageContentPart::TextContentPart { text: value } =>
+									text.push(value),
+								OpenAIUserMessageContentPart::ImageContentPart { image_url } =>
+									match image_url.url.split_once(";base64,") {
+										Some((_, data)) if image_url.url.starts_with("data:") =>
+											images.push(data.to_string()),
+										// Ollama only accepts inline images.
+										_ => image_urls.push(image_url.url),
+									},
+							}
+						}
+						OllamaChatMessage {
+							images: if images.is_empty() { None } else { Some(images) },
+							..message_with_content("user", text.join("\n"))
+						}
+					},
+				},
+				OpenAIChatCompletionMessage::AssistantMessage { content, tool_calls, .. } => {
+					let mut calls = Vec::<OllamaChatToolCall>::new();
+					for call in tool_calls.unwrap_or_default() {
+						let arguments = call.function.arguments_object().ok_or_else(|| {
+							TransformationError::InvalidToolCallArguments {
+								tool_call_id: call.id.clone(),
+							}
+						})?;
+						tool_names.insert(call.id, call.function.name.clone());
+						calls.push(OllamaChatToolCall {
+							function: OllamaChatToolCallFunction {
+								name: call.function.name,
+								arguments,
+							},
+						});
+					}
+					OllamaChatMessage {
+						tool_calls: if calls.is_empty() { None } else { Some(calls) },
+						..message_with_content("assistant", content.unwrap_or_default())
+					}
+				},
+				OpenAIChatCompletionMessage::ToolMessage { content, tool_call_id } =>
+					OllamaChatMessage {
+						tool_name: tool_names.get(&tool_call_id).cloned(),
+						..message_with_content("tool", content)
+					},
+			});
+		}
+
+		Ok(Transformation {
+			request: OllamaChatRequest {
+				model: context.model.unwrap_or_else(|| self.model.clone()),
+				messages,
+				tools: self.tools.clone().map(|tls| {
+					tls.into_iter()
+						.map(|tool| match tool {
+							OpenAIChatCompletionTool::FunctionTool { function } =>
+								OllamaChatTool::FunctionTool {
+									function: OllamaChatToolFunction {
+										name: function.name,
+										description: function.description,
+										parameters: function.parameters,
+									},
+								},
+						})
+						.collect()
+				}),
+				format: self.response_format.clone().and_then(format),
+				options: Some(OllamaChatOptions {
+					temperature: self.temperature,
+					top_p: self.top_p,
+					top_k: None,
+					seed: self.seed,
+					num_predict: self.max_tokens.map(|max_tokens| max_tokens as i64),
+					num_ctx: context.num_ctx,
+					stop: self.stop.clone().map(|stop| match stop {
+						OpenAIChatCompletionStop::StringStop(v) => vec![v],
+						OpenAIChatCompletionStop::ArrayStop(v) => v,
+					}),
+					presence_penalty: self.presence_penalty,
+					frequency_penalty: self.frequency_penalty,
+				})
+				.filter(|options| *options != OllamaChatOptions::default()),
+				// Ollama streams by default, while OpenAI doesn't.
+				stream: Some(self.stream.unwrap_or(false)),
+				keep_alive: context.keep_alive,
+			},
+			loss: TransformationLoss {
+				n: self.n,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				logit_bias: self.logit_bias.clone(),
+				user: self.user.clone(),
+				tool_choice: self.tool_choice.clone(),
+				image_urls,
+			},
+		})
+	}
+}
+
+fn message_with_content(role: &str, content: String) -> OllamaChatMessage {
+	OllamaChatMessage {
+		role: role.to_string(),
+		content,
+		images: None,
+		tool_calls: None,
+		tool_name: None,
+	}
+}
+
+/// Ollama takes `json` for JSON mode and the schema itself for structured outputs.
+fn format(format: serde_json::Value) -> Option<serde_json::Value> {
+	match format.get("type").and_then(|value| value.as_str()) {
+		Some("json_object") => Some(serde_json::Value::String("json".to_string())),
+		Some("json_schema") =>
+			format.get("json_schema").and_then(|value| value.get("schema")).cloned(),
+		_ => None,
+	}
+}
+
+pub struct TransformationLoss {
+	pub n: Option<u64>,
+	pub logprobs: Option<bool>,
+	pub top_logprobs: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub user: Option<String>,
+	/// Ollama always lets the model decide whether to call a tool.
+	pub tool_choice: Option<OpenAIChatCompletionToolChoice>,
+	/// Image URLs that were dropped, as Ollama only accepts base64 encoded images.
+	pub image_urls: Vec<String>,
+}
+
+pub struct TransformationContext {
+	/// The name of the model on the Ollama server, when it differs from the requested one.
+	pub model: Option<String>,
+	pub num_ctx: Option<u64>,
+	pub keep_alive: Option<serde_json::Value>,
+}
+
+pub struct Transformation {
+	pub request: OllamaChatRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// The arguments of an assistant tool call are not a valid JSON object.
+	InvalidToolCallArguments { tool_call_id: String },
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::InvalidToolCallArguments { tool_call_id } =>
+				write!(f, "arguments of tool call `{tool_call_id}` are not a valid JSON object"),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	fn fx_context() -> TransformationContext {
+		TransformationContext { model: None, num_ctx: None, keep_alive: None }
+	}
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "gpt-4o",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{
+			  "role": "user",
+			  "content": [
+				{ "type": "text", "text": "What's in these images?" },
+				{ "type": "image_url", "image_url": { "url": "data:image/png;base64,iVBORw0KGgo=" } },
+				{ "type": "image_url", "image_url": { "url": "https://example.com/image.jpg" } }
+			  ]
+			}
+		  ],
+		  "max_tokens": 100,
+		  "response_format": { "type": "json_object" },
+		  "n": 2
+		}))?;
+
+		let data = fx_request.to_ollama_api(TransformationContext {
+			model: Some("llava:13b".to_string()),
+			..fx_context()
+		})?;
+
+		assert_eq!(data.request.model, "llava:13b");
+		assert_eq!(data.request.stream, Some(false));
+		assert_eq!(data.request.format, Some(json!("json")));
+		assert_eq!(data.request.options.unwrap().num_predict, Some(100));
+		assert_eq!(data.request.messages[1].images, Some(vec!["iVBORw0KGgo=".to_string()]));
+
+		// Check if the image URL and unsupported parameters were passed to the loss object.
+		assert_eq!(data.loss.image_urls, vec!["https://example.com/image.jpg".to_string()]);
+		assert_eq!(data.loss.n, Some(2));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tool_messages_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "llama3.2",
+		  "messages": [
+			{ "role": "user", "content": "What is the weather today in Paris?" },
+			{
+			  "role": "assistant",
+			  "tool_calls": [{ "id": "call_1", "type": "function", "function": { "name": "get_current_weather", "arguments": "{\"location\": \"Paris, FR\"}" } }]
+			},
+			{ "role": "tool", "content": "22 degrees", "tool_call_id": "call_1" }
+		  ]
+		}))?;
+
+		let data = fx_request.to_ollama_api(fx_context())?;
+
+		assert_eq!(data.request.options, None);
+		assert_eq!(
+			data.request.messages[1].tool_calls,
+			Some(vec![OllamaChatToolCall {
+				function: OllamaChatToolCallFunction {
+					name: "get_current_weather".to_string(),
+					arguments: json!({ "location": "Paris, FR" })
+				}
+			}])
+		);
+		assert_eq!(data.request.messages[2].tool_name, Some("get_current_weather".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_empty_tool_arguments_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "llama3.2",
+		  "messages": [
+			{
+			  "role": "assistant",
+			  "tool_calls": [{ "id": "call_1", "type": "function", "function": { "name": "get_time", "arguments": "" } }]
+			}
+		  ]
+		}))?;
+
+		let data = fx_request.to_ollama_api(fx_context())?;
+
+		assert_eq!(
+			data.request.messages[0].tool_calls,
+			Some(vec![OllamaChatToolCall {
+				function: OllamaChatToolCallFunction {
+					name: "get_time".to_string(),
+					arguments: json!({})
+				}
+			}])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_non_object_tool_arguments_request_transform_fail() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "llama3.2",
+		  "messages": [
+			{
+			  "role": "assistant",
+			  "tool_calls": [{ "id": "call_1", "type": "function", "function": { "name": "get_current_weather", "arguments": "[1]" } }]
+			}
+		  ]
+		}))?;
+
+		let data = fx_request.to_ollama_api(fx_context());
+
+		assert_eq!(
+			data.err(),
+			Some(TransformationError::InvalidToolCallArguments {
+				tool_call_id: "call_1".to_string()
+			})
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
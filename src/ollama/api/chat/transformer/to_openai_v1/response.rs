@@ -0,0 +1,267 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionChunkResponseChoice as OpenAIChatCompletionChunkResponseChoice,
+	ChatCompletionChunkResponseChoiceDelta as OpenAIChatCompletionChunkResponseChoiceDelta,
+	ChatCompletionChunkResponseChoiceFunctionToolCall as OpenAIChatCompletionChunkResponseChoiceFunctionToolCall,
+	ChatCompletionChunkResponseChoiceToolCall as OpenAIChatCompletionChunkResponseChoiceToolCall,
+	ChatCompletionChunkResponseChoiceToolCallType as OpenAIChatCompletionChunkResponseChoiceToolCallType,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionObjectResponseChoice as OpenAIChatCompletionObjectResponseChoice,
+	ChatCompletionObjectResponseChoiceMessage as OpenAIChatCompletionObjectResponseChoiceMessage,
+	ChatCompletionObjectResponseChoiceToolCall as OpenAIChatCompletionObjectResponseChoiceToolCall,
+	ChatCompletionResponseChoiceFunctionToolCall as OpenAIChatCompletionResponseChoiceFunctionToolCall,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+};
+
+use crate::ollama::api::chat::response::ChatResponse as OllamaChatResponse;
+
+// region:    --- Object Response
+impl OllamaChatResponse {
+	pub fn to_openai_v1(&self, context: TransformationContext) -> Transformation {
+		let tool_calls = self.message.tool_calls.clone().unwrap_or_default();
+
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: context.id.clone(),
+				choices: vec![OpenAIChatCompletionObjectResponseChoice {
+					finish_reason: self.finish_reason(),
+					index: 0,
+					message: OpenAIChatCompletionObjectResponseChoiceMessage {
+						content: Some(self.message.content.clone()),
+						role: self.message.role.clone(),
+						tool_calls: if tool_calls.is_empty() {
+							None
+						} else {
+							Some(
+								tool_calls
+									.into_iter()
+									.enumerate()
+									.map(|(index, call)| OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+										id: tool_call_id(&context.id, index),
+										function: OpenAIChatCompletionResponseChoiceFunctionToolCall { name: call.function.name, arguments: call.function.arguments.to_string() },
+									})
+									.collect(),
+							)
+						},
+						annotations: None,
+					},
+					logprobs: None,
+				}],
+				created: context.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: self.usage(),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				created_at: self.created_at.clone(),
+				thinking: self.message.thinking.clone(),
+			},
+		}
+	}
+
+	fn finish_reason(&self) -> String {
+		if self.message.tool_calls.as_ref().is_some_and(|calls| !calls.is_empty()) {
+			return "tool_calls".to_string();
+		}
+		match self.done_reason.as_deref() {
+			Some("length") => "length".to_string(),
+			_ => "stop".to_string(),
+		}
+	}
+
+	fn usage(&self) -> OpenAIChatCompletionResponseUsage {
+		let prompt_tokens = self.prompt_eval_count.unwrap_or(0);
+		let completion_tokens = self.eval_count.unwrap_or(0);
+
+		OpenAIChatCompletionResponseUsage {
+			completion_tokens,
+			prompt_tokens,
+			total_tokens: prompt_tokens + completion_tokens,
+			prompt_tokens_details: None,
+		}
+	}
+}
+
+/// Ollama doesn't assign ids to tool calls, so they are derived from the completion id.
+fn tool_call_id(id: &str, index: usize) -> String {
+	format!("call_{id}_{index}")
+}
+
+pub struct TransformationLoss {
+	/// The RFC 3339 creation time, which is replaced by the timestamp given in the context.
+	pub created_at: String,
+	pub thinking: Option<String>,
+}
+
+/// Ollama doesn't return an id, and its creation time is not a timestamp, so both have to be
+/// provided by the caller.
+pub struct TransformationContext {
+	pub id: String,
+	pub created: u64,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Response
+impl OllamaChatResponse {
+	/// Transforms one line of a streamed `/api/chat` response. The context has to be shared by
+	/// every line of the same stream, as Ollama only sends the role once and doesn't index tool
+	/// calls.
+	pub fn to_openai_v1_chunk(
+		&self,
+		context: &mut StreamTransformationContext,
+	) -> OpenAIChatCompletionChunkResponse {
+		let tool_calls = self.message.tool_calls.clone().unwrap_or_default();
+		let role = if context.role_sent { None } else { Some(self.message.role.clone()) };
+		context.role_sent = true;
+
+		let tool_calls: Vec<OpenAIChatCompletionChunkResponseChoiceToolCall> = tool_calls
+			.into_iter()
+			.map(|call| {
+				let index = context.tool_calls;
+				context.tool_calls += 1;
+
+				OpenAIChatCompletionChunkResponseChoiceToolCall {
+					index: index as u64,
+					id: Some(tool_call_id(&context.id, index)),
+					r#type: Some(OpenAIChatCompletionChunkResponseChoiceToolCallType::FunctionType),
+					function: Some(OpenAIChatCompletionChunkResponseChoiceFunctionToolCall {
+						name: Some(call.function.name),
+						arguments: Some(call.function.arguments.to_string()),
+					}),
+				}
+			})
+			.collect();
+
+		OpenAIChatCompletionChunkResponse {
+			id: context.id.clone(),
+			choices: vec![OpenAIChatCompletionChunkResponseChoice {
+				finish_reason: if self.done {
+					// The tool calls are sent in an earlier line than the one with `done`.
+					Some(if context.tool_calls > 0 {
+						"tool_calls".to_string()
+					} else {
+						self.finish_reason()
+					})
+				} else {
+					None
+				},
+				index: 0,
+				delta: OpenAIChatCompletionChunkResponseChoiceDelta {
+					content: Some(self.message.content.clone())
+						.filter(|content| !content.is_empty()),
+					role,
+					tool_calls: if tool_calls.is_empty() { None } else { Some(tool_calls) },
+					annotations: None,
+				},
+				logprobs: None,
+			}],
+			created: context.created,
+			model: self.model.clone(),
+			system_fingerprint: None,
+			object: "chat.completion.chunk".to_string(),
+			// Ollama only sends the counts with the last line, so the usage is added to it.
+			usage: if self.done && context.include_usage { Some(self.usage()) } else { None },
+			service_tier: None,
+		}
+	}
+}
+
+pub struct StreamTransformationContext {
+	pub id: String,
+	pub created: u64,
+	/// Add the usage to the last chunk, like OpenAI does with `stream_options.include_usage`.
+	pub include_usage: bool,
+	role_sent: bool,
+	tool_calls: usize,
+}
+
+impl StreamTransformationContext {
+	pub fn new(id: String, created: u64, include_usage: bool) -> Self {
+		StreamTransformationContext { id, created, include_usage, role_sent: false, tool_calls: 0 }
+	}
+}
+// endregion: --- Stream Response
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: OllamaChatResponse = serde_json::from_value(json!({
+		  "model": "llama3.2",
+		  "created_at": "2024-07-22T20:33:28.123648Z",
+		  "message": {
+			"role": "assistant",
+			"content": "",
+			"tool_calls": [{ "function": { "name": "get_current_weather", "arguments": { "location": "Paris, FR" } } }]
+		  },
+		  "done_reason": "stop",
+		  "done": true,
+		  "prompt_eval_count": 215,
+		  "eval_count": 21
+		}))?;
+
+		let data = fx_response.to_openai_v1(TransformationContext {
+			id: "chatcmpl-123".to_string(),
+			created: 1700000000,
+		});
+
+		assert_eq!(data.response.id, "chatcmpl-123");
+		assert_eq!(data.response.choices[0].finish_reason, "tool_calls");
+		assert_eq!(
+			data.response.choices[0].message.tool_calls,
+			Some(vec![OpenAIChatCompletionObjectResponseChoiceToolCall::FunctionTool {
+				id: "call_chatcmpl-123_0".to_string(),
+				function: OpenAIChatCompletionResponseChoiceFunctionToolCall {
+					name: "get_current_weather".to_string(),
+					arguments: "{\"location\":\"Paris, FR\"}".to_string()
+				},
+			}])
+		);
+		assert_eq!(data.response.usage.total_tokens, 236);
+
+		// Check if the creation time was passed to the loss object.
+		assert_eq!(data.loss.created_at, "2024-07-22T20:33:28.123648Z");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stream_response_transform_ok() -> Result<()> {
+		let fx_lines: Vec<OllamaChatResponse> = serde_json::from_value(json!([
+		  { "model": "llama3.2", "created_at": "2023-08-04T08:52:19.385406455-07:00", "message": { "role": "assistant", "content": "The" }, "done": false },
+		  { "model": "llama3.2", "created_at": "2023-08-04T08:52:19.385406455-07:00", "message": { "role": "assistant", "content": " sky" }, "done": false },
+		  { "model": "llama3.2", "created_at": "2023-08-04T19:22:45.499127Z", "message": { "role": "assistant", "content": "" }, "done": true, "done_reason": "length", "prompt_eval_count": 26, "eval_count": 290 }
+		]))?;
+
+		let mut context =
+			StreamTransformationContext::new("chatcmpl-123".to_string(), 1700000000, true);
+		let data: Vec<OpenAIChatCompletionChunkResponse> =
+			fx_lines.iter().map(|line| line.to_openai_v1_chunk(&mut context)).collect();
+
+		assert_eq!(data[0].choices[0].delta.role, Some("assistant".to_string()));
+		assert_eq!(data[1].choices[0].delta.role, None);
+		assert_eq!(data[1].choices[0].delta.content, Some(" sky".to_string()));
+		assert_eq!(data[2].choices[0].delta.content, None);
+		assert_eq!(data[2].choices[0].finish_reason, Some("length".to_string()));
+		assert_eq!(data[2].usage.as_ref().map(|usage| usage.total_tokens), Some(316));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
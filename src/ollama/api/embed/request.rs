@@ -0,0 +1,69 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedRequest {
+	/// The name of the model to generate embeddings from.
+	pub model: String,
+
+	/// Text or list of text to generate embeddings for.
+	pub input: EmbedRequestInput,
+
+	/// default: true
+	/// Truncates the end of each input to fit within the context length. Returns an error if
+	/// false and the context length is exceeded.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub truncate: Option<bool>,
+
+	/// Number of dimensions to generate embeddings for, for models that support it.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub dimensions: Option<i64>,
+
+	/// Additional model parameters listed in the documentation for the Modelfile.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub options: Option<serde_json::Value>,
+
+	/// default: 5m
+	/// Controls how long the model will stay loaded into memory following the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub keep_alive: Option<serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum EmbedRequestInput {
+	String(String),
+	ArrayString(Vec<String>),
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embed_request_ollama_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "all-minilm",
+		  "input": ["Why is the sky blue?", "Why is the grass green?"]
+		})
+		.to_string();
+
+		let data: EmbedRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(
+			data.input,
+			EmbedRequestInput::ArrayString(vec![
+				"Why is the sky blue?".to_string(),
+				"Why is the grass green?".to_string()
+			])
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,53 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedResponse {
+	/// The model name.
+	pub model: String,
+
+	/// One embedding per input, in the same order.
+	pub embeddings: Vec<Vec<f64>>,
+
+	/// Time spent generating the embeddings, in nanoseconds.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub total_duration: Option<u64>,
+
+	/// Time spent in nanoseconds loading the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub load_duration: Option<u64>,
+
+	/// Number of tokens in the input.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_eval_count: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embed_response_ollama_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "model": "all-minilm",
+		  "embeddings": [[0.010071029, -0.0017594862, 0.05007221], [-0.0098027075, 0.06042469, 0.025257962]],
+		  "total_duration": 14143917,
+		  "load_duration": 1019500,
+		  "prompt_eval_count": 8
+		})
+		.to_string();
+
+		let data: EmbedResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.embeddings.len(), 2);
+		assert_eq!(data.prompt_eval_count, Some(8));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,135 @@
+use crate::openai::v1::embeddings::request::{
+	EmbeddingsRequest as OpenAIEmbeddingsRequest,
+	EmbeddingsRequestInput as OpenAIEmbeddingsRequestInput,
+};
+
+use crate::ollama::api::embed::request::{
+	EmbedRequest as OllamaEmbedRequest, EmbedRequestInput as OllamaEmbedRequestInput,
+};
+
+impl OpenAIEmbeddingsRequest {
+	pub fn to_ollama_api(
+		&self,
+		context: TransformationContext,
+	) -> Result<Transformation, TransformationError> {
+		let input = match self.input.clone() {
+			OpenAIEmbeddingsRequestInput::String(value) => OllamaEmbedRequestInput::String(value),
+			OpenAIEmbeddingsRequestInput::ArrayString(values) =>
+				OllamaEmbedRequestInput::ArrayString(values),
+			OpenAIEmbeddingsRequestInput::ArrayInt(_)
+			| OpenAIEmbeddingsRequestInput::ArrayArrayInt(_) =>
+				return Err(TransformationError::TokenInputNotSupported),
+		};
+
+		// Ollama only returns floats, so any other encoding is reported as lost.
+		let encoding_format = match self.encoding_format.as_deref() {
+			None | Some("float") => None,
+			Some(_) => self.encoding_format.clone(),
+		};
+
+		Ok(Transformation {
+			request: OllamaEmbedRequest {
+				model: context.model.unwrap_or_else(|| self.model.clone()),
+				input,
+				truncate: None,
+				dimensions: self.dimensions,
+				options: None,
+				keep_alive: context.keep_alive,
+			},
+			loss: TransformationLoss { encoding_format, user: self.user.clone() },
+		})
+	}
+}
+
+pub struct TransformationLoss {
+	pub encoding_format: Option<String>,
+	pub user: Option<String>,
+}
+
+pub struct TransformationContext {
+	/// The name of the model on the Ollama server, when it differs from the requested one.
+	pub model: Option<String>,
+	pub keep_alive: Option<serde_json::Value>,
+}
+
+pub struct Transformation {
+	pub request: OllamaEmbedRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// Ollama only embeds text, so token array inputs can't be forwarded.
+	TokenInputNotSupported,
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::TokenInputNotSupported =>
+				write!(f, "token array inputs are not supported by Ollama"),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayString(vec![
+				"hello".to_string(),
+				"world".to_string(),
+			]),
+			model: "text-embedding-3-small".to_string(),
+			encoding_format: Some("base64".to_string()),
+			dimensions: None,
+			user: Some("user-1234".to_string()),
+		};
+
+		let data = fx_request.to_ollama_api(TransformationContext {
+			model: Some("nomic-embed-text".to_string()),
+			keep_alive: None,
+		})?;
+
+		assert_eq!(data.request.model, "nomic-embed-text");
+		assert_eq!(
+			data.request.input,
+			OllamaEmbedRequestInput::ArrayString(vec!["hello".to_string(), "world".to_string()])
+		);
+
+		// Check if the parameters Ollama can't honor were passed to the loss object.
+		assert_eq!(data.loss.encoding_format, Some("base64".to_string()));
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_token_input_request_transform_fail() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayInt(vec![1, 2, 3]),
+			model: "nomic-embed-text".to_string(),
+			encoding_format: None,
+			dimensions: None,
+			user: None,
+		};
+
+		let data =
+			fx_request.to_ollama_api(TransformationContext { model: None, keep_alive: None });
+
+		assert_eq!(data.err(), Some(TransformationError::TokenInputNotSupported));
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,71 @@
+use crate::openai::v1::embeddings::response::{
+	EmbeddingsResponse as OpenAIEmbeddingsResponse,
+	EmbeddingsResponseData as OpenAIEmbeddingsResponseData,
+	EmbeddingsResponseEmbedding as OpenAIEmbeddingsResponseEmbedding,
+	EmbeddingsResponseUsage as OpenAIEmbeddingsResponseUsage,
+};
+
+use crate::ollama::api::embed::response::EmbedResponse as OllamaEmbedResponse;
+
+impl OllamaEmbedResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		let prompt_tokens = self.prompt_eval_count.unwrap_or(0);
+
+		Transformation {
+			response: OpenAIEmbeddingsResponse {
+				object: "list".to_string(),
+				data: self
+					.embeddings
+					.clone()
+					.into_iter()
+					.enumerate()
+					.map(|(index, embedding)| OpenAIEmbeddingsResponseData {
+						object: "embedding".to_string(),
+						embedding: OpenAIEmbeddingsResponseEmbedding::Float(embedding),
+						index: index as u64,
+					})
+					.collect(),
+				model: self.model.clone(),
+				usage: OpenAIEmbeddingsResponseUsage { prompt_tokens, total_tokens: prompt_tokens },
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct Transformation {
+	pub response: OpenAIEmbeddingsResponse,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_basic_response_transform_ok() -> Result<()> {
+		let fx_response = OllamaEmbedResponse {
+			model: "nomic-embed-text".to_string(),
+			embeddings: vec![vec![0.1, 0.2], vec![0.3, 0.4]],
+			total_duration: None,
+			load_duration: None,
+			prompt_eval_count: Some(4),
+		};
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.data.len(), 2);
+		assert_eq!(data.response.data[1].index, 1);
+		assert_eq!(data.response.usage.total_tokens, 4);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,203 @@
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionRequest, UserMessageContent, UserMessageContentPart,
+};
+
+/// The chat completion features supported by a provider model. Requests can be checked against
+/// it before being forwarded, instead of relying on the provider to reject them.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChatCompletionCapabilities {
+	/// Whether image content parts are accepted in user messages.
+	pub vision: bool,
+	/// Whether tools, tool calls and tool messages are accepted.
+	pub tools: bool,
+	/// Whether `response_format` can be `{ "type": "json_object" }`.
+	pub json_mode: bool,
+	/// Whether `response_format` can be `{ "type": "json_schema", ... }`.
+	pub json_schema: bool,
+	/// Whether `logprobs` and `top_logprobs` are accepted.
+	pub logprobs: bool,
+	/// The maximum value accepted for `max_tokens`, if the model has one.
+	pub max_output_tokens: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum UnsupportedCapability {
+	Vision,
+	Tools,
+	JsonMode,
+	JsonSchema,
+	Logprobs,
+	MaxOutputTokens { requested: u64, max: u64 },
+}
+
+impl std::fmt::Display for UnsupportedCapability {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			UnsupportedCapability::Vision =>
+				write!(f, "image inputs are not supported by this model"),
+			UnsupportedCapability::Tools => write!(f, "tools are not supported by this model"),
+			UnsupportedCapability::JsonMode =>
+				write!(f, "response_format `json_object` is not supported by this model"),
+			UnsupportedCapability::JsonSchema =>
+				write!(f, "response_format `json_schema` is not supported by this model"),
+			UnsupportedCapability::Logprobs =>
+				write!(f, "logprobs are not supported by this model"),
+			UnsupportedCapability::MaxOutputTokens { requested, max } => write!(
+				f,
+				"max_tokens is {requested}, but this model supports at most {max} output tokens"
+			),
+		}
+	}
+}
+
+impl std::error::Error for UnsupportedCapability {}
+
+impl ChatCompletionRequest {
+	/// Returns every feature used by the request that the given capabilities don't cover. An
+	/// empty list means the request can be forwarded as is.
+	pub fn unsupported_capabilities(
+		&self,
+		capabilities: &ChatCompletionCapabilities,
+	) -> Vec<UnsupportedCapability> {
+		let mut unsupported = Vec::new();
+
+		if !capabilities.vision && self.uses_vision() {
+			unsupported.push(UnsupportedCapability::Vision);
+		}
+		if !capabilities.tools && self.uses_tools() {
+			unsupported.push(UnsupportedCapability::Tools);
+		}
+
+		let response_format_type = self
+			.response_format
+			.as_ref()
+			.and_then(|format| format.get("type"))
+			.and_then(|value| value.as_str());
+		if !capabilities.json_mode && response_format_type == Some("json_object") {
+			unsupported.push(UnsupportedCapability::JsonMode);
+		}
+		if !capabilities.json_schema && response_format_type == Some("json_schema") {
+			unsupported.push(UnsupportedCapability::JsonSchema);
+		}
+
+		if !capabilities.logprobs && (self.logprobs == Some(true) || self.top_logprobs.is_some()) {
+			unsupported.push(UnsupportedCapability::Logprobs);
+		}
+
+		if let (Some(requested), Some(max)) = (self.max_tokens, capabilities.max_output_tokens) {
+			if requested > max {
+				unsupported.push(UnsupportedCapability::MaxOutputTokens { requested, max });
+			}
+		}
+
+		unsupported
+	}
+
+	fn uses_vision(&self) -> bool {
+		self.messages.iter().any(|message| match message {
+			ChatCompletionMessage::UserMessage {
+				content: UserMessageContent::ArrayContentParts(parts),
+				..
+			} => parts
+				.iter()
+				.any(|part| matches!(part, UserMessageContentPart::ImageContentPart { .. })),
+			_ => false,
+		})
+	}
+
+	fn uses_tools(&self) -> bool {
+		let has_tools = self.tools.as_ref().is_some_and(|tools| !tools.is_empty());
+		let has_tool_messages = self.messages.iter().any(|message| match message {
+			ChatCompletionMessage::AssistantMessage { tool_calls, .. } =>
+				tool_calls.as_ref().is_some_and(|calls| !calls.is_empty()),
+			ChatCompletionMessage::ToolMessage { .. } => true,
+			_ => false,
+		});
+
+		has_tools || has_tool_messages
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	fn fx_capabilities() -> ChatCompletionCapabilities {
+		ChatCompletionCapabilities {
+			vision: false,
+			tools: false,
+			json_mode: true,
+			json_schema: false,
+			logprobs: false,
+			max_output_tokens: Some(4096),
+		}
+	}
+
+	#[test]
+	fn test_supported_request_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request: ChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "gpt-4o",
+		  "messages": [
+			{
+			  "role": "user",
+			  "content": "Hello!"
+			}
+		  ],
+		  "response_format": { "type": "json_object" },
+		  "max_tokens": 4096
+		}))?;
+
+		assert_eq!(fx_request.unsupported_capabilities(&fx_capabilities()), vec![]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_unsupported_request_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request: ChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "gpt-4o",
+		  "messages": [
+			{
+			  "role": "user",
+			  "content": [
+				{
+				  "type": "image_url",
+				  "image_url": { "url": "https://example.com/image.jpg" }
+				}
+			  ]
+			},
+			{
+			  "role": "tool",
+			  "content": "sunny",
+			  "tool_call_id": "call_abc123"
+			}
+		  ],
+		  "response_format": { "type": "json_schema", "json_schema": { "name": "answer" } },
+		  "logprobs": true,
+		  "max_tokens": 8192
+		}))?;
+
+		assert_eq!(
+			fx_request.unsupported_capabilities(&fx_capabilities()),
+			vec![
+				UnsupportedCapability::Vision,
+				UnsupportedCapability::Tools,
+				UnsupportedCapability::JsonSchema,
+				UnsupportedCapability::Logprobs,
+				UnsupportedCapability::MaxOutputTokens { requested: 8192, max: 4096 },
+			]
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionResponseAnnotation, ChatCompletionResponseUrlCitation,
+};
+
+/// A web source of a search augmented response, referenced in the content as `[n]`, where `n` is
+/// the position of the source starting at 1. Providers that only return URLs use them as titles.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CitationSource {
+	pub title: String,
+	pub url: String,
+}
+
+/// The URL citations found in the content of a message.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Citations {
+	/// One annotation per `[n]` marker, spanning the marker.
+	pub annotations: Vec<ChatCompletionResponseAnnotation>,
+	/// The sources the content never refers to, which have no place in the annotations.
+	pub unreferenced: Vec<CitationSource>,
+}
+
+impl Citations {
+	/// Finds the `[n]` markers in the content. Markers that don't refer to one of the sources are
+	/// left alone.
+	pub fn from_content(content: &str, sources: &[CitationSource]) -> Self {
+		let chars: Vec<char> = content.chars().collect();
+		let mut annotations = Vec::new();
+		let mut referenced = HashSet::new();
+		let mut start = 0;
+
+		while start < chars.len() {
+			let digits = chars[start + 1..].iter().take_while(|c| c.is_ascii_digit()).count();
+			let end = start + 1 + digits;
+			if chars[start] == '[' && digits > 0 && chars.get(end) == Some(&']') {
+				let number: usize =
+					chars[start + 1..end].iter().collect::<String>().parse().unwrap_or(0);
+				if let Some(source) = number.checked_sub(1).and_then(|index| sources.get(index)) {
+					annotations.push(ChatCompletionResponseAnnotation::UrlCitation {
+						url_citation: ChatCompletionResponseUrlCitation {
+							start_index: start as u64,
+							end_index: end as u64,
+							url: source.url.clone(),
+							title: source.title.clone(),
+						},
+					});
+					referenced.insert(number - 1);
+					start = end;
+				}
+			}
+			start += 1;
+		}
+
+		Citations {
+			annotations,
+			unreferenced: sources
+				.iter()
+				.enumerate()
+				.filter(|(index, _)| !referenced.contains(index))
+				.map(|(_, source)| source.clone())
+				.collect(),
+		}
+	}
+
+	/// The annotations to set on the message, which are left out when there are none.
+	pub fn message_annotations(&self) -> Option<Vec<ChatCompletionResponseAnnotation>> {
+		if self.annotations.is_empty() {
+			None
+		} else {
+			Some(self.annotations.clone())
+		}
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	fn fx_source(title: &str, url: &str) -> CitationSource {
+		CitationSource { title: title.to_string(), url: url.to_string() }
+	}
+
+	#[test]
+	fn test_citations_from_content_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_sources = vec![
+			fx_source("The Milky Way", "https://science.nasa.gov/milky-way"),
+			fx_source("Gaia", "https://esa.int/gaia"),
+			fx_source("Example", "https://example.com"),
+		];
+
+		let data =
+			Citations::from_content("Über 100 billion stars[2][1], [4] or [x].", &fx_sources);
+
+		assert_eq!(
+			data.annotations,
+			vec![
+				ChatCompletionResponseAnnotation::UrlCitation {
+					url_citation: ChatCompletionResponseUrlCitation {
+						start_index: 22,
+						end_index: 24,
+						url: "https://esa.int/gaia".to_string(),
+						title: "Gaia".to_string()
+					}
+				},
+				ChatCompletionResponseAnnotation::UrlCitation {
+					url_citation: ChatCompletionResponseUrlCitation {
+						start_index: 25,
+						end_index: 27,
+						url: "https://science.nasa.gov/milky-way".to_string(),
+						title: "The Milky Way".to_string()
+					}
+				},
+			]
+		);
+		assert_eq!(data.unreferenced, vec![fx_source("Example", "https://example.com")]);
+		assert_eq!(Citations::from_content("No sources.", &fx_sources).message_annotations(), None);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
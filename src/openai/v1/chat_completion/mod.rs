@@ -1,2 +1,5 @@
+pub mod capabilities;
+pub mod citations;
+pub mod quirks;
 pub mod request;
 pub mod response;
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionRequest, ChatCompletionTool, ChatCompletionToolChoice,
+	ChatCompletionToolChoiceObject, UserMessageContent, UserMessageContentPart,
+};
+
+/// The ways in which a mostly OpenAI compatible server (vLLM, LM Studio, LiteLLM, TGI, ...)
+/// deviates from the OpenAI API. Requests are adjusted to them before being forwarded, instead of
+/// letting the server reject them.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ChatCompletionQuirks {
+	/// System messages are rejected, so they are prepended to the first user message.
+	pub no_system_role: bool,
+	/// Only string tool choices are accepted, so a named function choice becomes `required`, with
+	/// the tools narrowed down to the named function.
+	pub no_named_tool_choice: bool,
+	/// Only one choice can be generated per request.
+	pub no_multiple_choices: bool,
+	/// `seed` is rejected.
+	pub no_seed: bool,
+	/// `logit_bias` is rejected.
+	pub no_logit_bias: bool,
+}
+
+/// The parameters that were removed from the request because of the quirks.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct QuirksLoss {
+	pub n: Option<u64>,
+	pub seed: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+	/// The tools removed so that `required` can only call the named function.
+	pub tools: Option<Vec<ChatCompletionTool>>,
+}
+
+pub struct QuirksAdjustment {
+	pub request: ChatCompletionRequest,
+	pub loss: QuirksLoss,
+}
+
+impl ChatCompletionRequest {
+	/// Returns a copy of the request that the server described by the quirks accepts, along with
+	/// the parameters that had to be removed.
+	pub fn with_quirks(&self, quirks: &ChatCompletionQuirks) -> QuirksAdjustment {
+		let mut request = self.clone();
+		let mut loss = QuirksLoss::default();
+
+		if quirks.no_system_role {
+			request.messages = fold_system_messages(request.messages);
+		}
+		if quirks.no_named_tool_choice {
+			if let Some(ChatCompletionToolChoice::FunctionChoice(
+				ChatCompletionToolChoiceObject::FunctionTool { function },
+			)) = &request.tool_choice
+			{
+				let name = function.name.clone();
+				if let Some(tools) = request.tools.take() {
+					let (tools, removed): (Vec<_>, Vec<_>) = tools.into_iter().partition(
+						|ChatCompletionTool::FunctionTool { function }| function.name == name,
+					);
+					request.tools = Some(tools);
+					loss.tools = if removed.is_empty() { None } else { Some(removed) };
+				}
+				loss.tool_choice = request.tool_choice.take();
+				request.tool_choice =
+					Some(ChatCompletionToolChoice::StringChoice("required".to_string()));
+			}
+		}
+		if quirks.no_multiple_choices && request.n.is_some_and(|n| n > 1) {
+			loss.n = request.n.take();
+		}
+		if quirks.no_seed {
+			loss.seed = request.seed.take();
+		}
+		if quirks.no_logit_bias {
+			loss.logit_bias = request.logit_bias.take();
+		}
+
+		QuirksAdjustment { request, loss }
+	}
+}
+
+fn fold_system_messages(messages: Vec<ChatCompletionMessage>) -> Vec<ChatCompletionMessage> {
+	let (system, mut messages): (Vec<_>, Vec<_>) = messages
+		.into_iter()
+		.partition(|message| matches!(message, ChatCompletionMessage::SystemMessage { .. }));
+
+	let system = system
+		.into_iter()
+		.filter_map(|message| match message {
+			ChatCompletionMessage::SystemMessage { content, .. } => Some(content),
+			_ => None,
+		})
+		.collect::<Vec<String>>()
+		.join("\n\n");
+	if system.is_empty() {
+		return messages;
+	}
+
+	match messages.first_mut() {
+		Some(ChatCompletionMessage::UserMessage { content, .. }) => {
+			*content = match content.clone() {
+				UserMessageContent::TextContent(text) =>
+					UserMessageContent::TextContent(format!("{system}\n\n{text}")),
+				UserMessageContent::ArrayContentParts(mut parts) => {
+					parts.insert(0, UserMessageContentPart::TextContentPart { text: system });
+					UserMessageContent::ArrayContentParts(parts)
+				},
+			};
+		},
+		_ => messages.insert(
+			0,
+			ChatCompletionMessage::UserMessage {
+				name: None,
+				content: UserMessageContent::TextContent(system),
+			},
+		),
+	}
+
+	messages
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_no_quirks_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request: ChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "meta-llama/Llama-3.1-8B-Instruct",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "Hello!" }
+		  ],
+		  "seed": 42
+		}))?;
+
+		let data = fx_request.with_quirks(&ChatCompletionQuirks::default());
+
+		assert_eq!(data.request, fx_request);
+		assert_eq!(data.loss, QuirksLoss::default());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_all_quirks_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request: ChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "mistralai/Mistral-7B-Instruct-v0.3",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "system", "content": "Answer in French." },
+			{ "role": "user", "content": "Hello!" }
+		  ],
+		  "tools": [
+			{ "type": "function", "function": { "name": "get_current_weather" } },
+			{ "type": "function", "function": { "name": "get_current_time" } }
+		  ],
+		  "tool_choice": { "type": "function", "function": { "name": "get_current_weather" } },
+		  "n": 2,
+		  "seed": 42,
+		  "logit_bias": { "1234": -100 }
+		}))?;
+		let fx_quirks = ChatCompletionQuirks {
+			no_system_role: true,
+			no_named_tool_choice: true,
+			no_multiple_choices: true,
+			no_seed: true,
+			no_logit_bias: true,
+		};
+
+		let data = fx_request.with_quirks(&fx_quirks);
+
+		assert_eq!(
+			data.request.messages,
+			vec![ChatCompletionMessage::UserMessage {
+				name: None,
+				content: UserMessageContent::TextContent(
+					"You are a helpful assistant.\n\nAnswer in French.\n\nHello!".to_string()
+				),
+			}]
+		);
+		assert_eq!(
+			data.request.tool_choice,
+			Some(ChatCompletionToolChoice::StringChoice("required".to_string()))
+		);
+		assert_eq!(data.request.tools.as_ref().map(Vec::len), Some(1));
+		assert_eq!(data.request.n, None);
+		assert_eq!(data.request.seed, None);
+		assert_eq!(data.request.logit_bias, None);
+
+		// Check if the removed parameters were passed to the loss object.
+		assert_eq!(data.loss.tool_choice, fx_request.tool_choice);
+		assert_eq!(data.loss.tools, fx_request.tools.as_ref().map(|tools| tools[1..].to_vec()));
+		assert_eq!(data.loss.n, Some(2));
+		assert_eq!(data.loss.seed, Some(42));
+		assert_eq!(data.loss.logit_bias, fx_request.logit_bias);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -107,8 +107,30 @@ pub struct ChatCompletionRequest {
 	/// are present. `auto` is the default if tools are present.
 	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
 	pub tool_choice: Option<ChatCompletionToolChoice>,
+
+	/// Constrains effort on reasoning for reasoning models. Reducing reasoning effort can result
+	/// in faster responses and fewer tokens used on reasoning in a response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub reasoning_effort: Option<ChatCompletionReasoningEffort>,
+}
+
+// region:    --- ChatCompletionReasoningEffort
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatCompletionReasoningEffort {
+	#[cfg_attr(feature = "serde", serde(rename = "minimal", alias = "minimal"))]
+	Minimal,
+	#[cfg_attr(feature = "serde", serde(rename = "low", alias = "low"))]
+	Low,
+	#[cfg_attr(feature = "serde", serde(rename = "medium", alias = "medium"))]
+	Medium,
+	#[cfg_attr(feature = "serde", serde(rename = "high", alias = "high"))]
+	High,
 }
 
+// endregion: --- ChatCompletionReasoningEffort
+
 // region:    --- ChatCompletionStop
 
 #[derive(Debug, PartialEq, Clone)]
@@ -199,6 +221,18 @@ pub struct AssistantToolCallFunction {
 	pub arguments: String,
 }
 
+impl AssistantToolCallFunction {
+	/// The arguments as the JSON object most providers expect, while OpenAI sends them as a JSON
+	/// encoded string, which is empty for functions without parameters. `None` when the arguments
+	/// are not a JSON object.
+	pub fn arguments_object(&self) -> Option<serde_json::Value> {
+		if self.arguments.trim().is_empty() {
+			return Some(serde_json::json!({}));
+		}
+		serde_json::from_str(&self.arguments).ok().filter(serde_json::Value::is_object)
+	}
+}
+
 // endregion: --- Chat Completion Message Content
 // endregion: --- ChatCompletionMessage
 
@@ -599,6 +633,7 @@ mod tests {
 			logit_bias: None,
 			tools: None,
 			tool_choice: None,
+			reasoning_effort: None,
 		};
 		let expected_request = json!({
 		  "model": "my-model",
@@ -56,6 +56,9 @@ pub struct ChatCompletionObjectResponseChoiceMessage {
 	pub role: String,
 	/// The tool calls generated by the model, such as function calls.
 	pub tool_calls: Option<Vec<ChatCompletionObjectResponseChoiceToolCall>>,
+	/// Annotations for the message, such as the web pages cited when using web search.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub annotations: Option<Vec<ChatCompletionResponseAnnotation>>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -119,7 +122,7 @@ pub struct ChatCompletionChunkResponseChoice {
 	pub logprobs: Option<ChatCompletionResponseChoiceLogprob>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Default, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionChunkResponseChoiceDelta {
 	/// The contents of the message
@@ -130,13 +133,43 @@ pub struct ChatCompletionChunkResponseChoiceDelta {
 	/// The tool calls generated by the model, such as function calls.
 	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
 	pub tool_calls: Option<Vec<ChatCompletionChunkResponseChoiceToolCall>>,
+	/// Annotations for the message, such as the web pages cited when using web search.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub annotations: Option<Vec<ChatCompletionResponseAnnotation>>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
-pub enum ChatCompletionChunkResponseChoiceToolCall {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoiceToolCall {
+	/// The index of the tool call in the list of tool calls.
+	pub index: u64,
+	/// The ID of the tool call. Only sent in the first chunk of each tool call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub id: Option<String>,
+	/// The type of the tool. Only sent in the first chunk of each tool call.
+	#[cfg_attr(feature = "serde", serde(rename = "type", skip_serializing_if = "Option::is_none"))]
+	pub r#type: Option<ChatCompletionChunkResponseChoiceToolCallType>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub function: Option<ChatCompletionChunkResponseChoiceFunctionToolCall>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatCompletionChunkResponseChoiceToolCallType {
 	#[cfg_attr(feature = "serde", serde(rename = "function", alias = "function"))]
-	FunctionTool { index: u64, id: String, function: ChatCompletionResponseChoiceFunctionToolCall },
+	FunctionType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponseChoiceFunctionToolCall {
+	/// The name of the function to call. Only sent in the first chunk of each tool call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub name: Option<String>,
+	/// A fragment of the arguments to call the function with, as generated by the model in JSON
+	/// format.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub arguments: Option<String>,
 }
 // endregion: --- Chunk Response
 
@@ -144,11 +177,23 @@ pub enum ChatCompletionChunkResponseChoiceToolCall {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionResponseUsage {
 	/// Number of tokens in the generated completion.
-	completion_tokens: u64,
+	pub completion_tokens: u64,
 	/// Number of tokens in the prompt.
-	prompt_tokens: u64,
+	pub prompt_tokens: u64,
 	/// Total number of tokens used in the request (prompt + completion).
-	total_tokens: u64,
+	pub total_tokens: u64,
+	/// Breakdown of tokens used in the prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_tokens_details: Option<ChatCompletionResponseUsagePromptTokensDetails>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsagePromptTokensDetails {
+	/// Cached tokens present in the prompt, which are included in `prompt_tokens` and billed at
+	/// a discounted rate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cached_tokens: Option<u64>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -194,11 +239,31 @@ pub struct ChatCompletionResponseChoiceTopLogprobContent {
 	pub bytes: Option<Vec<u8>>,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ChatCompletionResponseAnnotation {
+	#[cfg_attr(feature = "serde", serde(rename = "url_citation", alias = "url_citation"))]
+	UrlCitation { url_citation: ChatCompletionResponseUrlCitation },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUrlCitation {
+	/// The index of the first character of the URL citation in the message.
+	pub start_index: u64,
+	/// The index of the last character of the URL citation in the message.
+	pub end_index: u64,
+	/// The URL of the web resource.
+	pub url: String,
+	/// The title of the web resource.
+	pub title: String,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChatCompletionResponseChoiceFunctionToolCall {
-	name: String,
-	arguments: String,
+	pub name: String,
+	pub arguments: String,
 }
 
 // region:    --- Tests
@@ -513,6 +578,43 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_response_object_cached_tokens_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "id": "chatcmpl-123",
+		  "object": "chat.completion",
+		  "created": 1677652288,
+		  "model": "gpt-4o-2024-08-06",
+		  "choices": [{
+			"index": 0,
+			"message": {
+			  "role": "assistant",
+			  "content": "Hello there, how may I assist you today?",
+			},
+			"logprobs": null,
+			"finish_reason": "stop"
+		  }],
+		  "usage": {
+			"prompt_tokens": 2006,
+			"completion_tokens": 300,
+			"total_tokens": 2306,
+			"prompt_tokens_details": { "cached_tokens": 1920 }
+		  }
+		}
+		)
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(
+			data.usage.prompt_tokens_details,
+			Some(ChatCompletionResponseUsagePromptTokensDetails { cached_tokens: Some(1920) })
+		);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_response_chunk_example_01_decode_ok() -> Result<()> {
 		// -- Setup & Fixtures
@@ -565,6 +667,41 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_response_chunk_tool_call_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_first_chunk = json!(
+			{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o","choices":[{"index":0,"delta":{"role":"assistant","content":null,"tool_calls":[{"index":0,"id":"call_abc123","type":"function","function":{"name":"get_current_weather","arguments":""}}]},"logprobs":null,"finish_reason":null}]}
+		)
+		.to_string();
+		let fx_next_chunk = json!(
+			{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"location\""}}]},"logprobs":null,"finish_reason":null}]}
+		)
+		.to_string();
+
+		let first: ChatCompletionChunkResponse = serde_json::from_str(&fx_first_chunk).unwrap();
+		let next: ChatCompletionChunkResponse = serde_json::from_str(&fx_next_chunk).unwrap();
+
+		let first_call = &first.choices[0].delta.tool_calls.as_ref().unwrap()[0];
+		assert_eq!(first_call.id, Some("call_abc123".to_string()));
+		assert_eq!(
+			first_call.r#type,
+			Some(ChatCompletionChunkResponseChoiceToolCallType::FunctionType)
+		);
+
+		let next_call = &next.choices[0].delta.tool_calls.as_ref().unwrap()[0];
+		assert_eq!(next_call.id, None);
+		assert_eq!(
+			next_call.function,
+			Some(ChatCompletionChunkResponseChoiceFunctionToolCall {
+				name: None,
+				arguments: Some("{\"location\"".to_string())
+			})
+		);
+
+		Ok(())
+	}
 }
 
 // endregion:    --- Tests
@@ -0,0 +1,118 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbeddingsResponse {
+	/// The object type, which is always "list".
+	pub object: String,
+
+	/// The list of embeddings generated by the model.
+	pub data: Vec<EmbeddingsResponseData>,
+
+	/// The name of the model used to generate the embedding.
+	pub model: String,
+
+	/// The usage information for the request.
+	pub usage: EmbeddingsResponseUsage,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbeddingsResponseData {
+	/// The object type, which is always "embedding".
+	pub object: String,
+
+	/// The embedding vector, which is a list of floats. The length of vector depends on the model
+	/// as listed in the [embedding guide](https://platform.openai.com/docs/guides/embeddings).
+	pub embedding: EmbeddingsResponseEmbedding,
+
+	/// The index of the embedding in the list of embeddings.
+	pub index: u64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum EmbeddingsResponseEmbedding {
+	Float(Vec<f64>),
+	Base64(String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbeddingsResponseUsage {
+	/// The number of tokens used by the prompt.
+	pub prompt_tokens: u64,
+	/// The total number of tokens used by the request.
+	pub total_tokens: u64,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embeddings_response_openai_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "object": "list",
+		  "data": [
+			{
+			  "object": "embedding",
+			  "embedding": [0.0023064255, -0.009327292, -0.0028842222],
+			  "index": 0
+			}
+		  ],
+		  "model": "text-embedding-ada-002",
+		  "usage": {
+			"prompt_tokens": 8,
+			"total_tokens": 8
+		  }
+		})
+		.to_string();
+
+		let data: EmbeddingsResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(
+			data.data[0].embedding,
+			EmbeddingsResponseEmbedding::Float(vec![0.0023064255, -0.009327292, -0.0028842222])
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_embeddings_response_base64_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "object": "list",
+		  "data": [
+			{
+			  "object": "embedding",
+			  "embedding": "AAAAAA==",
+			  "index": 0
+			}
+		  ],
+		  "model": "text-embedding-3-small",
+		  "usage": {
+			"prompt_tokens": 8,
+			"total_tokens": 8
+		  }
+		})
+		.to_string();
+
+		let data: EmbeddingsResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(
+			data.data[0].embedding,
+			EmbeddingsResponseEmbedding::Base64("AAAAAA==".to_string())
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
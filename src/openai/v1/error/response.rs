@@ -0,0 +1,86 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponse {
+	pub error: ErrorResponseError,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorResponseError {
+	/// A human-readable error message.
+	pub message: String,
+
+	/// The error type, such as `invalid_request_error` or `server_error`.
+	#[cfg_attr(feature = "serde", serde(rename = "type"))]
+	pub r#type: String,
+
+	/// The request parameter that caused the error, if any.
+	pub param: Option<String>,
+
+	/// A machine-readable error code, such as `model_not_found` or `rate_limit_exceeded`.
+	pub code: Option<String>,
+
+	/// The original error payload when this error was normalized from another provider, keyed by
+	/// the provider name. Not part of the OpenAI API.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub vendor: Option<serde_json::Value>,
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_error_response_openai_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "error": {
+			"message": "The model `gpt-5` does not exist or you do not have access to it.",
+			"type": "invalid_request_error",
+			"param": null,
+			"code": "model_not_found"
+		  }
+		})
+		.to_string();
+
+		let data: ErrorResponse = serde_json::from_str(&fx_response).unwrap();
+
+		assert_eq!(data.error.r#type, "invalid_request_error");
+		assert_eq!(data.error.code, Some("model_not_found".to_string()));
+		assert_eq!(data.error.vendor, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_error_response_serializing_01_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_object = ErrorResponse {
+			error: ErrorResponseError {
+				message: "message".to_string(),
+				r#type: "server_error".to_string(),
+				param: None,
+				code: None,
+				vendor: None,
+			},
+		};
+
+		let data = serde_json::to_value(fx_object)?;
+
+		// OpenAI always sends `param` and `code`, even when they are null.
+		assert_eq!(
+			data,
+			json!({"error": {"message": "message", "type": "server_error", "param": null, "code": null}})
+		);
+
+		Ok(())
+	}
+}
+
+// endregion:    --- Tests
@@ -1,2 +1,4 @@
 pub mod chat_completion;
 pub mod embeddings;
+pub mod error;
+pub mod trace;
@@ -0,0 +1,82 @@
+/// The identifiers providers return in the response headers, to reference a request in support
+/// tickets. OpenAI and most compatible providers send `x-request-id`, Anthropic `request-id`, and
+/// Azure also sends the id of its API gateway and the region that served the request.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Trace {
+	/// `x-request-id`, or `request-id` for Anthropic.
+	pub request_id: Option<String>,
+	/// `apim-request-id`, sent by Azure.
+	pub apim_request_id: Option<String>,
+	/// `x-ms-region`, the Azure region that served the request, e.g. `East US`.
+	pub region: Option<String>,
+}
+
+impl Trace {
+	/// Reads the identifiers out of the response headers. Header names are matched case
+	/// insensitively, and empty values are ignored.
+	pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+		let mut trace = Trace::default();
+
+		for (name, value) in headers {
+			let value = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+			match name.to_ascii_lowercase().as_str() {
+				"x-request-id" | "request-id" => trace.request_id = value.or(trace.request_id),
+				"apim-request-id" => trace.apim_request_id = value,
+				"x-ms-region" => trace.region = value,
+				_ => {},
+			}
+		}
+
+		trace
+	}
+
+	/// The id to give the provider, preferring the one of the Azure gateway, which is what Azure
+	/// support asks for.
+	pub fn provider_request_id(&self) -> Option<&str> {
+		self.apim_request_id.as_deref().or(self.request_id.as_deref())
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_trace_headers_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_headers = [
+			("Content-Type", "application/json"),
+			("x-request-id", "f1c3e4d2-0a0b-4c5d-9e8f-7a6b5c4d3e2f"),
+			("apim-request-id", "9b2f5d7e-3c1a-4e6b-8d0f-2a4c6e8b0d1f"),
+			("x-ms-region", "East US"),
+		];
+
+		let data = Trace::from_headers(fx_headers);
+
+		assert_eq!(data.request_id, Some("f1c3e4d2-0a0b-4c5d-9e8f-7a6b5c4d3e2f".to_string()));
+		assert_eq!(data.region, Some("East US".to_string()));
+		assert_eq!(data.provider_request_id(), Some("9b2f5d7e-3c1a-4e6b-8d0f-2a4c6e8b0d1f"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_trace_anthropic_headers_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_headers = [("Request-Id", "req_018EeWyXxfu5pfWkrYcMdjWG"), ("X-Ms-Region", "")];
+
+		let data = Trace::from_headers(fx_headers);
+
+		assert_eq!(data.provider_request_id(), Some("req_018EeWyXxfu5pfWkrYcMdjWG"));
+		assert_eq!(data.region, None);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,52 @@
+/// The app attribution OpenRouter uses for its rankings. It is sent as request headers, and the
+/// referer is required for the app to show up.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Attribution {
+	/// The URL of the app, sent as `HTTP-Referer`.
+	pub referer: String,
+	/// The name of the app, sent as `X-Title`.
+	pub title: Option<String>,
+}
+
+impl Attribution {
+	/// Returns the headers to add to every request sent to OpenRouter.
+	pub fn headers(&self) -> Vec<(&'static str, String)> {
+		let mut headers = vec![("HTTP-Referer", self.referer.clone())];
+		if let Some(title) = &self.title {
+			headers.push(("X-Title", title.clone()));
+		}
+
+		headers
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_attribution_headers_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_attribution = Attribution {
+			referer: "https://llmur.dev".to_string(),
+			title: Some("llmur".to_string()),
+		};
+
+		assert_eq!(
+			fx_attribution.headers(),
+			vec![
+				("HTTP-Referer", "https://llmur.dev".to_string()),
+				("X-Title", "llmur".to_string())
+			]
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
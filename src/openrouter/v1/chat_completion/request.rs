@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionStop, ChatCompletionTool, ChatCompletionToolChoice,
+};
+
+/// OpenRouter accepts the OpenAI request, extended with routing and accounting options.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// The model to use, prefixed with its organization, e.g. `openai/gpt-4o`.
+	pub model: String,
+
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// Fallback models, tried in order if the primary model fails or is unavailable.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub models: Option<Vec<String>>,
+
+	/// Preferences for the providers the request is routed to.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub provider: Option<ProviderPreferences>,
+
+	/// Whether to include the detailed usage, with cost, in the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<UsageOptions>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub n: Option<u64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<bool>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_logprobs: Option<i64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<ChatCompletionStop>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub user: Option<String>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logit_bias: Option<HashMap<String, i32>>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProviderPreferences {
+	/// Providers to try in order, e.g. `["anthropic", "openai"]`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub order: Option<Vec<String>>,
+
+	/// default: true
+	/// Whether to allow backup providers when the preferred ones are unavailable.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub allow_fallbacks: Option<bool>,
+
+	/// default: false
+	/// Whether to only use providers that support all parameters of the request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub require_parameters: Option<bool>,
+
+	/// default: allow
+	/// Whether to use providers that may store data, `allow` or `deny`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub data_collection: Option<String>,
+
+	/// Providers to allow for this request, excluding all others.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub only: Option<Vec<String>>,
+
+	/// Providers to skip for this request.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub ignore: Option<Vec<String>>,
+
+	/// Quantization levels to filter providers by, e.g. `["fp8"]`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub quantizations: Option<Vec<String>>,
+
+	/// Sort providers by `price`, `throughput` or `latency` instead of load balancing them.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub sort: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsageOptions {
+	pub include: bool,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_request_openrouter_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "meta-llama/llama-3.1-70b-instruct",
+		  "messages": [{ "role": "user", "content": "Hello" }],
+		  "provider": { "order": ["openai", "together"], "allow_fallbacks": false },
+		  "usage": { "include": true }
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(
+			data.provider.and_then(|provider| provider.order),
+			Some(vec!["openai".to_string(), "together".to_string()])
+		);
+		assert_eq!(data.usage, Some(UsageOptions { include: true }));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,150 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponseChoice, ChatCompletionObjectResponseChoice,
+	ChatCompletionResponseUsagePromptTokensDetails,
+};
+
+/// The choices have the OpenAI shape, while the response also names the provider that served it
+/// and the usage carries its cost.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponse {
+	pub id: String,
+
+	/// The provider the request was routed to.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub provider: Option<String>,
+
+	pub model: String,
+
+	/// The object type, which is always `chat.completion`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system_fingerprint: Option<String>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	pub id: String,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub provider: Option<String>,
+
+	pub model: String,
+
+	/// The object type, which is always `chat.completion.chunk`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system_fingerprint: Option<String>,
+
+	/// Only sent with the last chunk.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsage {
+	pub prompt_tokens: u64,
+	pub completion_tokens: u64,
+	pub total_tokens: u64,
+	/// The cost of the request in credits, only returned when the usage is requested with
+	/// `usage.include`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cost: Option<f64>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_tokens_details: Option<ChatCompletionResponseUsagePromptTokensDetails>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub completion_tokens_details: Option<ChatCompletionResponseUsageCompletionTokensDetails>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsageCompletionTokensDetails {
+	/// Tokens generated for reasoning, which are included in `completion_tokens`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub reasoning_tokens: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_object_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "gen-1747906612-nsjVKEdNgxsjexDONLGF",
+		  "provider": "Together",
+		  "model": "meta-llama/llama-3.1-70b-instruct",
+		  "object": "chat.completion",
+		  "created": 1747906612,
+		  "choices": [
+			{
+			  "logprobs": null,
+			  "finish_reason": "stop",
+			  "native_finish_reason": "eos",
+			  "index": 0,
+			  "message": { "role": "assistant", "content": "Hello! How can I help you today?", "refusal": null, "reasoning": null }
+			}
+		  ],
+		  "usage": {
+			"prompt_tokens": 194,
+			"completion_tokens": 2,
+			"total_tokens": 196,
+			"cost": 0.00095,
+			"prompt_tokens_details": { "cached_tokens": 0 },
+			"completion_tokens_details": { "reasoning_tokens": 0 }
+		  }
+		})
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.provider, Some("Together".to_string()));
+		assert_eq!(data.usage.and_then(|usage| usage.cost), Some(0.00095));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_response_chunk_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "gen-1747906612-nsjVKEdNgxsjexDONLGF",
+		  "provider": "Together",
+		  "model": "meta-llama/llama-3.1-70b-instruct",
+		  "object": "chat.completion.chunk",
+		  "created": 1747906612,
+		  "choices": [{ "index": 0, "delta": { "role": "assistant", "content": "Hello" }, "finish_reason": null, "native_finish_reason": null, "logprobs": null }]
+		})
+		.to_string();
+
+		let data: ChatCompletionChunkResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].delta.content, Some("Hello".to_string()));
+		assert_eq!(data.usage, None);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,90 @@
+use crate::openai::v1::chat_completion::request::ChatCompletionRequest as OpenAIChatCompletionRequest;
+
+use crate::openrouter::v1::chat_completion::request::{
+	ChatCompletionRequest as OpenRouterChatCompletionRequest,
+	ProviderPreferences as OpenRouterProviderPreferences, UsageOptions as OpenRouterUsageOptions,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_openrouter_v1(&self, context: TransformationContext) -> Transformation {
+		Transformation {
+			request: OpenRouterChatCompletionRequest {
+				model: self.model.clone(),
+				messages: self.messages.clone(),
+				models: context.models,
+				provider: context.provider,
+				// Always ask for the detailed usage, so the cost can be logged.
+				usage: Some(OpenRouterUsageOptions { include: true }),
+				n: self.n,
+				frequency_penalty: self.frequency_penalty,
+				temperature: self.temperature,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				max_tokens: self.max_tokens,
+				presence_penalty: self.presence_penalty,
+				top_p: self.top_p,
+				stream: self.stream,
+				stop: self.stop.clone(),
+				user: self.user.clone(),
+				seed: self.seed,
+				response_format: self.response_format.clone(),
+				logit_bias: self.logit_bias.clone(),
+				tools: self.tools.clone(),
+				tool_choice: self.tool_choice.clone(),
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+/// The routing options, usually taken from the connection.
+pub struct TransformationContext {
+	pub models: Option<Vec<String>>,
+	pub provider: Option<OpenRouterProviderPreferences>,
+}
+
+pub struct Transformation {
+	pub request: OpenRouterChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "openai/gpt-4o",
+		  "messages": [{ "role": "user", "content": "Hello!" }],
+		  "temperature": 0.5
+		}))?;
+		let fx_provider = OpenRouterProviderPreferences {
+			order: Some(vec!["azure".to_string()]),
+			data_collection: Some("deny".to_string()),
+			..Default::default()
+		};
+
+		let data = fx_request.to_openrouter_v1(TransformationContext {
+			models: None,
+			provider: Some(fx_provider.clone()),
+		});
+
+		assert_eq!(data.request.messages, fx_request.messages);
+		assert_eq!(data.request.temperature, Some(0.5));
+		assert_eq!(data.request.provider, Some(fx_provider));
+		assert_eq!(data.request.usage, Some(OpenRouterUsageOptions { include: true }));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
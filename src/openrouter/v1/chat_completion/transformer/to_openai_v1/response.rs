@@ -0,0 +1,147 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+};
+
+use crate::openrouter::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenRouterChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as OpenRouterChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as OpenRouterChatCompletionResponseUsage,
+};
+
+// region:    --- Object Response
+impl OpenRouterChatCompletionObjectResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: self.choices.clone(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: self.system_fingerprint.clone(),
+				object: "chat.completion".to_string(),
+				usage: self.usage.as_ref().map(usage).unwrap_or(
+					OpenAIChatCompletionResponseUsage {
+						completion_tokens: 0,
+						prompt_tokens: 0,
+						total_tokens: 0,
+						prompt_tokens_details: None,
+					},
+				),
+				service_tier: None,
+			},
+			loss: loss(&self.provider, &self.usage),
+		}
+	}
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Chunk Response
+impl OpenRouterChatCompletionChunkResponse {
+	pub fn to_openai_v1(&self) -> ChunkTransformation {
+		ChunkTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices: self.choices.clone(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: self.system_fingerprint.clone(),
+				object: "chat.completion.chunk".to_string(),
+				usage: self.usage.as_ref().map(usage),
+				service_tier: None,
+			},
+			loss: loss(&self.provider, &self.usage),
+		}
+	}
+}
+
+pub struct ChunkTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Chunk Response
+
+/// What OpenRouter reports beyond the OpenAI usage, to be kept in the request log.
+pub struct TransformationLoss {
+	pub provider: Option<String>,
+	/// The cost of the request in credits.
+	pub cost: Option<f64>,
+	pub reasoning_tokens: Option<u64>,
+}
+
+fn loss(
+	provider: &Option<String>,
+	usage: &Option<OpenRouterChatCompletionResponseUsage>,
+) -> TransformationLoss {
+	TransformationLoss {
+		provider: provider.clone(),
+		cost: usage.as_ref().and_then(|usage| usage.cost),
+		reasoning_tokens: usage
+			.as_ref()
+			.and_then(|usage| usage.completion_tokens_details.as_ref())
+			.and_then(|details| details.reasoning_tokens),
+	}
+}
+
+fn usage(usage: &OpenRouterChatCompletionResponseUsage) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: usage.completion_tokens,
+		prompt_tokens: usage.prompt_tokens,
+		total_tokens: usage.total_tokens,
+		prompt_tokens_details: usage.prompt_tokens_details.clone(),
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: OpenRouterChatCompletionObjectResponse = serde_json::from_value(json!({
+		  "id": "gen-1747906612-nsjVKEdNgxsjexDONLGF",
+		  "provider": "OpenAI",
+		  "model": "openai/o3-mini",
+		  "object": "chat.completion",
+		  "created": 1747906612,
+		  "choices": [{ "finish_reason": "stop", "index": 0, "message": { "role": "assistant", "content": "Hello!" } }],
+		  "usage": {
+			"prompt_tokens": 194,
+			"completion_tokens": 66,
+			"total_tokens": 260,
+			"cost": 0.0005,
+			"prompt_tokens_details": { "cached_tokens": 128 },
+			"completion_tokens_details": { "reasoning_tokens": 64 }
+		  }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.usage.total_tokens, 260);
+		assert_eq!(
+			data.response.usage.prompt_tokens_details.and_then(|details| details.cached_tokens),
+			Some(128)
+		);
+
+		// Check if the provider and cost were passed to the loss object.
+		assert_eq!(data.loss.provider, Some("OpenAI".to_string()));
+		assert_eq!(data.loss.cost, Some(0.0005));
+		assert_eq!(data.loss.reasoning_tokens, Some(64));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
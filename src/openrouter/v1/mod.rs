@@ -0,0 +1,2 @@
+pub mod attribution;
+pub mod chat_completion;
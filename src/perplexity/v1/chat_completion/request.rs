@@ -0,0 +1,121 @@
+use crate::openai::v1::chat_completion::request::ChatCompletionMessage;
+
+/// The Perplexity request. The messages have the OpenAI shape, while the search the model runs
+/// before answering can be narrowed down.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// The name of the model that will complete your prompt, e.g. `sonar-pro`.
+	pub model: String,
+
+	/// A list of messages comprising the conversation so far.
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// The maximum number of completion tokens returned by the API.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// default: 0.2
+	/// The amount of randomness in the response, valued between 0 inclusive and 2 exclusive.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// default: 0.9
+	/// The nucleus sampling threshold, valued between 0 and 1 inclusive.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// default: 0
+	/// The number of tokens to keep for highest top-k filtering, between 0 and 2048 inclusive.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// default: false
+	/// Determines whether or not to incrementally stream the response with server-sent events.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// default: 0
+	/// A value between -2.0 and 2.0. Positive values penalize new tokens based on whether they
+	/// appear in the text so far.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// default: 0
+	/// A multiplicative penalty greater than 0. Values greater than 1.0 penalize new tokens based
+	/// on their existing frequency in the text so far.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// Enables structured outputs with a JSON schema or a regex.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// A list of domains to limit the search to, or to exclude from it when prefixed with `-`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_domain_filter: Option<Vec<String>>,
+
+	/// Returns search results within the specified time interval: `month`, `week`, `day` or
+	/// `hour`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_recency_filter: Option<String>,
+
+	/// default: false
+	/// Determines whether search results should include images.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub return_images: Option<bool>,
+
+	/// default: false
+	/// Determines whether related questions should be returned.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub return_related_questions: Option<bool>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub web_search_options: Option<WebSearchOptions>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebSearchOptions {
+	/// default: low
+	/// How much search context is retrieved for the model, `low`, `medium` or `high`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_context_size: Option<String>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_request_perplexity_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "sonar",
+		  "messages": [
+			{ "role": "system", "content": "Be precise and concise." },
+			{ "role": "user", "content": "How many stars are there in our galaxy?" }
+		  ],
+		  "search_domain_filter": ["nasa.gov"],
+		  "web_search_options": { "search_context_size": "high" }
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.search_domain_filter, Some(vec!["nasa.gov".to_string()]));
+		assert_eq!(
+			data.web_search_options.and_then(|options| options.search_context_size),
+			Some("high".to_string())
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,132 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponseChoice, ChatCompletionObjectResponseChoice,
+};
+
+/// The choices have the OpenAI shape, while the sources the answer is based on are returned next
+/// to them.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponse {
+	pub id: String,
+
+	pub model: String,
+
+	/// The object type, which is always `chat.completion`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+
+	/// The URLs of the sources, in the order they are referenced as `[1]`, `[2]`, ... in the
+	/// content.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub citations: Option<Vec<String>>,
+
+	/// The sources with their titles, in the same order as `citations`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_results: Option<Vec<ChatCompletionResponseSearchResult>>,
+}
+
+/// The sources are repeated in every chunk.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	pub id: String,
+
+	pub model: String,
+
+	/// The object type, which is always `chat.completion.chunk`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub citations: Option<Vec<String>>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_results: Option<Vec<ChatCompletionResponseSearchResult>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseSearchResult {
+	pub title: String,
+	pub url: String,
+	/// The publication date of the page.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub date: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsage {
+	pub prompt_tokens: u64,
+	pub completion_tokens: u64,
+	pub total_tokens: u64,
+	/// The search context size used, `low`, `medium` or `high`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_context_size: Option<String>,
+	/// Tokens used for the citations, only reported by some models.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub citation_tokens: Option<u64>,
+	/// The number of searches run, only reported by some models.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub num_search_queries: Option<u64>,
+	/// Tokens used for reasoning, only reported by some models.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub reasoning_tokens: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_object_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "3c90c3cc-0d44-4b50-8888-8dd25736052a",
+		  "model": "sonar",
+		  "object": "chat.completion",
+		  "created": 1724369245,
+		  "choices": [
+			{
+			  "index": 0,
+			  "finish_reason": "stop",
+			  "message": { "role": "assistant", "content": "There are an estimated 100 to 400 billion stars in the Milky Way[1]." },
+			  "delta": { "role": "assistant", "content": "" }
+			}
+		  ],
+		  "usage": { "prompt_tokens": 9, "completion_tokens": 18, "total_tokens": 27, "search_context_size": "low" },
+		  "citations": ["https://science.nasa.gov/milky-way"],
+		  "search_results": [{ "title": "The Milky Way - NASA Science", "url": "https://science.nasa.gov/milky-way", "date": "2024-05-01" }]
+		})
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.citations.map(|citations| citations.len()), Some(1));
+		assert_eq!(
+			data.search_results.map(|results| results[0].title.clone()),
+			Some("The Milky Way - NASA Science".to_string())
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
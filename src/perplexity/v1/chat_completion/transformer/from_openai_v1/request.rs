@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop, ChatCompletionTool as OpenAIChatCompletionTool,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+};
+
+use crate::perplexity::v1::chat_completion::request::{
+	ChatCompletionRequest as PerplexityChatCompletionRequest,
+	WebSearchOptions as PerplexityWebSearchOptions,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_perplexity_v1(&self, context: TransformationContext) -> Transformation {
+		Transformation {
+			request: PerplexityChatCompletionRequest {
+				model: self.model.clone(),
+				messages: self.messages.clone(),
+				max_tokens: self.max_tokens,
+				temperature: self.temperature,
+				top_p: self.top_p,
+				top_k: None,
+				stream: self.stream,
+				presence_penalty: self.presence_penalty,
+				frequency_penalty: self.frequency_penalty,
+				response_format: self.response_format.clone(),
+				search_domain_filter: context.search_domain_filter,
+				search_recency_filter: context.search_recency_filter,
+				return_images: None,
+				return_related_questions: None,
+				web_search_options: context.web_search_options,
+			},
+			loss: TransformationLoss {
+				n: self.n,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				seed: self.seed,
+				logit_bias: self.logit_bias.clone(),
+				stop: self.stop.clone(),
+				tools: self.tools.clone(),
+				tool_choice: self.tool_choice.clone(),
+				user: self.user.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub n: Option<u64>,
+	pub logprobs: Option<bool>,
+	pub top_logprobs: Option<i64>,
+	pub seed: Option<i64>,
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub stop: Option<OpenAIChatCompletionStop>,
+	pub tools: Option<Vec<OpenAIChatCompletionTool>>,
+	pub tool_choice: Option<OpenAIChatCompletionToolChoice>,
+	pub user: Option<String>,
+}
+
+/// The search options, usually set on the connection.
+pub struct TransformationContext {
+	pub search_domain_filter: Option<Vec<String>>,
+	pub search_recency_filter: Option<String>,
+	pub web_search_options: Option<PerplexityWebSearchOptions>,
+}
+
+pub struct Transformation {
+	pub request: PerplexityChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "sonar-pro",
+		  "messages": [{ "role": "user", "content": "How many stars are there in our galaxy?" }],
+		  "temperature": 0.2,
+		  "seed": 42,
+		  "stop": "\n"
+		}))?;
+
+		let data = fx_request.to_perplexity_v1(TransformationContext {
+			search_domain_filter: Some(vec!["nasa.gov".to_string()]),
+			search_recency_filter: None,
+			web_search_options: None,
+		});
+
+		assert_eq!(data.request.messages, fx_request.messages);
+		assert_eq!(data.request.temperature, Some(0.2));
+		assert_eq!(data.request.search_domain_filter, Some(vec!["nasa.gov".to_string()]));
+
+		// Check if the parameters Perplexity doesn't accept were passed to the loss object.
+		assert_eq!(data.loss.seed, Some(42));
+		assert_eq!(data.loss.stop, Some(OpenAIChatCompletionStop::StringStop("\n".to_string())));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::{
+	citations::{CitationSource, Citations},
+	response::{
+		ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+		ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+		ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+	},
+};
+
+use crate::perplexity::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as PerplexityChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as PerplexityChatCompletionObjectResponse,
+	ChatCompletionResponseSearchResult as PerplexityChatCompletionResponseSearchResult,
+	ChatCompletionResponseUsage as PerplexityChatCompletionResponseUsage,
+};
+
+// region:    --- Object Response
+impl PerplexityChatCompletionObjectResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		let sources = sources(&self.citations, &self.search_results);
+		let mut citations = Vec::<Citations>::new();
+
+		let choices = self
+			.choices
+			.clone()
+			.into_iter()
+			.map(|mut choice| {
+				let choice_citations = Citations::from_content(
+					choice.message.content.as_deref().unwrap_or_default(),
+					&sources,
+				);
+				choice.message.annotations = choice_citations.message_annotations();
+				citations.push(choice_citations);
+				choice
+			})
+			.collect();
+
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices,
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: self.usage.as_ref().map(usage).unwrap_or(
+					OpenAIChatCompletionResponseUsage {
+						completion_tokens: 0,
+						prompt_tokens: 0,
+						total_tokens: 0,
+						prompt_tokens_details: None,
+					},
+				),
+				service_tier: None,
+			},
+			loss: loss(&self.usage, unreferenced(&sources, &citations)),
+		}
+	}
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Response
+impl PerplexityChatCompletionChunkResponse {
+	/// The citation markers can be split across chunks, so the content is collected in the
+	/// context and the annotations are sent with the chunk that finishes the choice.
+	pub fn to_openai_v1(&self, context: &mut StreamTransformationContext) -> StreamTransformation {
+		let sources = sources(&self.citations, &self.search_results);
+		if !sources.is_empty() {
+			context.sources = sources;
+		}
+
+		let mut citations = Vec::<Citations>::new();
+		let choices = self
+			.choices
+			.clone()
+			.into_iter()
+			.map(|mut choice| {
+				let content = context.contents.entry(choice.index).or_default();
+				content.push_str(choice.delta.content.as_deref().unwrap_or_default());
+				if choice.finish_reason.is_some() {
+					let choice_citations = Citations::from_content(content, &context.sources);
+					choice.delta.annotations = choice_citations.message_annotations();
+					citations.push(choice_citations);
+				}
+				choice
+			})
+			.collect();
+
+		// Perplexity sends the running usage with every chunk, so only the final one is kept.
+		let finished = !citations.is_empty();
+		let final_usage = self.usage.clone().filter(|_| finished);
+
+		StreamTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices,
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion.chunk".to_string(),
+				usage: final_usage.as_ref().map(usage),
+				service_tier: None,
+			},
+			loss: loss(
+				&final_usage,
+				if finished { unreferenced(&context.sources, &citations) } else { Vec::new() },
+			),
+		}
+	}
+}
+
+pub struct StreamTransformationContext {
+	contents: HashMap<u64, String>,
+	sources: Vec<CitationSource>,
+}
+
+impl StreamTransformationContext {
+	pub fn new() -> Self {
+		StreamTransformationContext { contents: HashMap::new(), sources: Vec::new() }
+	}
+}
+
+impl Default for StreamTransformationContext {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+pub struct StreamTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Stream Response
+
+pub struct TransformationLoss {
+	/// The sources the content never refers to, so they have no annotation.
+	pub unreferenced_sources: Vec<CitationSource>,
+	/// The parts of the Perplexity usage that don't exist in the OpenAI one.
+	pub search_context_size: Option<String>,
+	pub citation_tokens: Option<u64>,
+	pub num_search_queries: Option<u64>,
+	pub reasoning_tokens: Option<u64>,
+}
+
+fn loss(
+	usage: &Option<PerplexityChatCompletionResponseUsage>,
+	unreferenced_sources: Vec<CitationSource>,
+) -> TransformationLoss {
+	TransformationLoss {
+		unreferenced_sources,
+		search_context_size: usage.as_ref().and_then(|usage| usage.search_context_size.clone()),
+		citation_tokens: usage.as_ref().and_then(|usage| usage.citation_tokens),
+		num_search_queries: usage.as_ref().and_then(|usage| usage.num_search_queries),
+		reasoning_tokens: usage.as_ref().and_then(|usage| usage.reasoning_tokens),
+	}
+}
+
+fn usage(usage: &PerplexityChatCompletionResponseUsage) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: usage.completion_tokens,
+		prompt_tokens: usage.prompt_tokens,
+		total_tokens: usage.total_tokens,
+		prompt_tokens_details: None,
+	}
+}
+
+/// Older models only return the URLs of the sources, which then also serve as titles.
+fn sources(
+	citations: &Option<Vec<String>>,
+	search_results: &Option<Vec<PerplexityChatCompletionResponseSearchResult>>,
+) -> Vec<CitationSource> {
+	match (search_results, citations) {
+		(Some(results), _) if !results.is_empty() => results
+			.iter()
+			.map(|result| CitationSource { title: result.title.clone(), url: result.url.clone() })
+			.collect(),
+		(_, Some(urls)) =>
+			urls.iter().map(|url| CitationSource { title: url.clone(), url: url.clone() }).collect(),
+		_ => Vec::new(),
+	}
+}
+
+/// The sources that none of the choices refer to.
+fn unreferenced(sources: &[CitationSource], citations: &[Citations]) -> Vec<CitationSource> {
+	sources
+		.iter()
+		.filter(|source| citations.iter().all(|citations| citations.unreferenced.contains(source)))
+		.cloned()
+		.collect()
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+	use crate::openai::v1::chat_completion::response::{
+		ChatCompletionResponseAnnotation as OpenAIChatCompletionResponseAnnotation,
+		ChatCompletionResponseUrlCitation as OpenAIChatCompletionResponseUrlCitation,
+	};
+
+	fn fx_citation(
+		start_index: u64,
+		end_index: u64,
+		url: &str,
+		title: &str,
+	) -> OpenAIChatCompletionResponseAnnotation {
+		OpenAIChatCompletionResponseAnnotation::UrlCitation {
+			url_citation: OpenAIChatCompletionResponseUrlCitation {
+				start_index,
+				end_index,
+				url: url.to_string(),
+				title: title.to_string(),
+			},
+		}
+	}
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: PerplexityChatCompletionObjectResponse = serde_json::from_value(json!({
+		  "id": "3c90c3cc-0d44-4b50-8888-8dd25736052a",
+		  "model": "sonar",
+		  "object": "chat.completion",
+		  "created": 1724369245,
+		  "choices": [{ "index": 0, "finish_reason": "stop", "message": { "role": "assistant", "content": "About 100 billion stars[2][1]." } }],
+		  "usage": { "prompt_tokens": 9, "completion_tokens": 18, "total_tokens": 27, "search_context_size": "low" },
+		  "citations": ["https://science.nasa.gov/milky-way", "https://esa.int/gaia", "https://example.com"],
+		  "search_results": [
+			{ "title": "The Milky Way", "url": "https://science.nasa.gov/milky-way" },
+			{ "title": "Gaia", "url": "https://esa.int/gaia" },
+			{ "title": "Example", "url": "https://example.com" }
+		  ]
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(
+			data.response.choices[0].message.annotations,
+			Some(vec![
+				fx_citation(23, 25, "https://esa.int/gaia", "Gaia"),
+				fx_citation(26, 28, "https://science.nasa.gov/milky-way", "The Milky Way"),
+			])
+		);
+		assert_eq!(data.response.usage.total_tokens, 27);
+
+		// Check if the unreferenced source and the search context size were passed to the loss
+		// object.
+		assert_eq!(
+			data.loss.unreferenced_sources,
+			vec![CitationSource {
+				title: "Example".to_string(),
+				url: "https://example.com".to_string()
+			}]
+		);
+		assert_eq!(data.loss.search_context_size, Some("low".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stream_response_transform_ok() -> Result<()> {
+		let fx_chunks: Vec<PerplexityChatCompletionChunkResponse> = serde_json::from_value(
+			json!([
+			  {
+				"id": "3c90c3cc-0d44-4b50-8888-8dd25736052a",
+				"model": "sonar",
+				"object": "chat.completion.chunk",
+				"created": 1724369245,
+				"choices": [{ "index": 0, "finish_reason": null, "delta": { "role": "assistant", "content": "Hello [" } }],
+				"usage": { "prompt_tokens": 9, "completion_tokens": 2, "total_tokens": 11 },
+				"citations": ["https://example.com"]
+			  },
+			  {
+				"id": "3c90c3cc-0d44-4b50-8888-8dd25736052a",
+				"model": "sonar",
+				"object": "chat.completion.chunk",
+				"created": 1724369245,
+				"choices": [{ "index": 0, "finish_reason": "stop", "delta": { "content": "1]" } }],
+				"usage": { "prompt_tokens": 9, "completion_tokens": 3, "total_tokens": 12 },
+				"citations": ["https://example.com"]
+			  }
+			]),
+		)?;
+
+		let mut context = StreamTransformationContext::new();
+		let data: Vec<OpenAIChatCompletionChunkResponse> =
+			fx_chunks.iter().map(|chunk| chunk.to_openai_v1(&mut context).response).collect();
+
+		assert_eq!(data[0].choices[0].delta.annotations, None);
+		assert_eq!(data[0].usage, None);
+		assert_eq!(
+			data[1].choices[0].delta.annotations,
+			Some(vec![fx_citation(6, 8, "https://example.com", "https://example.com")])
+		);
+		assert_eq!(data[1].usage.as_ref().map(|usage| usage.total_tokens), Some(12));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
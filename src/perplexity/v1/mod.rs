@@ -0,0 +1 @@
+pub mod chat_completion;
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionTool, ChatCompletionToolChoice,
+};
+
+/// Together serves an OpenAI compatible API, so the messages and tools are the OpenAI ones. The
+/// request adds the sampling parameters of the open models it hosts.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// The name of the model to query.
+	pub model: String,
+
+	/// A list of messages comprising the conversation so far.
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// The maximum number of tokens to generate.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// A list of string sequences that will truncate (stop) inference text output.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<Vec<String>>,
+
+	/// A decimal number from 0-1 that determines the degree of randomness in the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// A percentage (also called the nucleus parameter) that's used to dynamically adjust the
+	/// number of choices for each predicted token based on the cumulative probabilities.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// An integer that's used to limit the number of choices for the next predicted word or token.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_k: Option<u64>,
+
+	/// A number that controls the diversity of generated text by reducing the likelihood of
+	/// repeated sequences. Higher values decrease repetition.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub repetition_penalty: Option<f64>,
+
+	/// A number between 0 and 1 that can be used as an alternative to top_p and top_k.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub min_p: Option<f64>,
+
+	/// A number between -2.0 and 2.0 where a positive value increases the likelihood of a model
+	/// talking about new topics.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// A number between -2.0 and 2.0 where a positive value decreases the likelihood of repeating
+	/// tokens that have already been mentioned.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// Adjusts the likelihood of specific tokens appearing in the generated output.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logit_bias: Option<HashMap<String, i32>>,
+
+	/// Seed value for reproducibility.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	/// The number of completions to generate for each prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub n: Option<u64>,
+
+	/// If true, stream tokens as Server-Sent Events as the model generates them instead of waiting
+	/// for the full model response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	/// The name of the moderation model used to validate tokens, e.g. `Meta-Llama/Llama-Guard-7b`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub safety_model: Option<String>,
+
+	/// An object specifying the format that the model must output.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// A list of tools the model may call.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	/// Controls which (if any) function is called by the model.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_request_together_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": "What are some fun things to do in New York?" }
+		  ],
+		  "max_tokens": 512,
+		  "stop": ["<|eot_id|>"],
+		  "top_k": 50,
+		  "repetition_penalty": 1.0
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.messages.len(), 2);
+		assert_eq!(data.top_k, Some(50));
+		assert_eq!(data.stop, Some(vec!["<|eot_id|>".to_string()]));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,124 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponseChoice, ChatCompletionObjectResponseChoice,
+};
+
+/// The choices have the OpenAI shape, while the usage and the top level carry a few extra fields.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponse {
+	pub id: String,
+
+	/// The object type, which is always `chat.completion`.
+	pub object: String,
+
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: u64,
+
+	pub model: String,
+
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+
+	/// The prompt as seen by the model, only returned when it is echoed.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt: Option<Vec<serde_json::Value>>,
+
+	/// Warnings about the request, e.g. about a truncated prompt.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub warnings: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	pub id: String,
+
+	/// The object type, which is always `chat.completion.chunk`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub model: String,
+
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	/// Only sent with the last chunk.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsage {
+	pub prompt_tokens: u64,
+	pub completion_tokens: u64,
+	pub total_tokens: u64,
+	/// The number of prompt tokens read from the prompt cache.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub cached_tokens: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_object_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "8448080b880415ea-SJC",
+		  "object": "chat.completion",
+		  "created": 1705939893,
+		  "model": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+		  "prompt": [],
+		  "choices": [
+			{
+			  "finish_reason": "eos",
+			  "seed": 1234,
+			  "logprobs": null,
+			  "index": 0,
+			  "message": { "role": "assistant", "content": "New York City is a vibrant destination." }
+			}
+		  ],
+		  "usage": { "prompt_tokens": 31, "completion_tokens": 9, "total_tokens": 40, "cached_tokens": 16 }
+		})
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].finish_reason, "eos");
+		assert_eq!(data.usage.and_then(|usage| usage.cached_tokens), Some(16));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_response_chunk_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "8448080b880415ea-SJC",
+		  "object": "chat.completion.chunk",
+		  "created": 1705939893,
+		  "model": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+		  "choices": [{ "index": 0, "text": " City", "logprobs": null, "finish_reason": null, "delta": { "token_id": 4409, "role": "assistant", "content": " City" } }],
+		  "usage": null
+		})
+		.to_string();
+
+		let data: ChatCompletionChunkResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].delta.content, Some(" City".to_string()));
+		assert_eq!(data.usage, None);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,103 @@
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop,
+};
+
+use crate::together::v1::chat_completion::request::ChatCompletionRequest as TogetherChatCompletionRequest;
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_together_v1(&self, context: TransformationContext) -> Transformation {
+		Transformation {
+			request: TogetherChatCompletionRequest {
+				model: self.model.clone(),
+				messages: self.messages.clone(),
+				max_tokens: self.max_tokens,
+				stop: self.stop.clone().map(|stop| match stop {
+					OpenAIChatCompletionStop::StringStop(v) => vec![v],
+					OpenAIChatCompletionStop::ArrayStop(v) => v,
+				}),
+				temperature: self.temperature,
+				top_p: self.top_p,
+				top_k: context.top_k,
+				repetition_penalty: context.repetition_penalty,
+				min_p: context.min_p,
+				presence_penalty: self.presence_penalty,
+				frequency_penalty: self.frequency_penalty,
+				logit_bias: self.logit_bias.clone(),
+				seed: self.seed,
+				n: self.n,
+				stream: self.stream,
+				safety_model: context.safety_model,
+				response_format: self.response_format.clone(),
+				tools: self.tools.clone(),
+				tool_choice: self.tool_choice.clone(),
+			},
+			loss: TransformationLoss {
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				user: self.user.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	/// Together returns log probabilities in a different shape, so they are not requested.
+	pub logprobs: Option<bool>,
+	pub top_logprobs: Option<i64>,
+	pub user: Option<String>,
+}
+
+/// The sampling parameters that only exist on Together, usually set on the connection.
+pub struct TransformationContext {
+	pub top_k: Option<u64>,
+	pub repetition_penalty: Option<f64>,
+	pub min_p: Option<f64>,
+	pub safety_model: Option<String>,
+}
+
+pub struct Transformation {
+	pub request: TogetherChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+		  "messages": [{ "role": "user", "content": "Hello!" }],
+		  "stop": "<|eot_id|>",
+		  "logprobs": true,
+		  "user": "user-1234"
+		}))?;
+
+		let data = fx_request.to_together_v1(TransformationContext {
+			top_k: Some(50),
+			repetition_penalty: None,
+			min_p: None,
+			safety_model: None,
+		});
+
+		assert_eq!(data.request.messages, fx_request.messages);
+		assert_eq!(data.request.stop, Some(vec!["<|eot_id|>".to_string()]));
+		assert_eq!(data.request.top_k, Some(50));
+
+		// Check if the parameters Together can't honor were passed to the loss object.
+		assert_eq!(data.loss.logprobs, Some(true));
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,173 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+	ChatCompletionResponseUsagePromptTokensDetails as OpenAIChatCompletionResponseUsagePromptTokensDetails,
+};
+
+use crate::together::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as TogetherChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as TogetherChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as TogetherChatCompletionResponseUsage,
+};
+
+// region:    --- Object Response
+impl TogetherChatCompletionObjectResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: self
+					.choices
+					.clone()
+					.into_iter()
+					.map(|mut choice| {
+						choice.finish_reason = finish_reason(&choice.finish_reason);
+						choice
+					})
+					.collect(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: self.usage.as_ref().map(usage).unwrap_or(
+					OpenAIChatCompletionResponseUsage {
+						completion_tokens: 0,
+						prompt_tokens: 0,
+						total_tokens: 0,
+						prompt_tokens_details: None,
+					},
+				),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				prompt: self.prompt.clone(),
+				warnings: self.warnings.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub prompt: Option<Vec<serde_json::Value>>,
+	pub warnings: Option<Vec<serde_json::Value>>,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Chunk Response
+impl TogetherChatCompletionChunkResponse {
+	pub fn to_openai_v1(&self) -> ChunkTransformation {
+		ChunkTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices: self
+					.choices
+					.clone()
+					.into_iter()
+					.map(|mut choice| {
+						choice.finish_reason = choice.finish_reason.as_deref().map(finish_reason);
+						choice
+					})
+					.collect(),
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: None,
+				object: "chat.completion.chunk".to_string(),
+				usage: self.usage.as_ref().map(usage),
+				service_tier: None,
+			},
+			loss: ChunkTransformationLoss {},
+		}
+	}
+}
+
+pub struct ChunkTransformationLoss {}
+
+pub struct ChunkTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: ChunkTransformationLoss,
+}
+// endregion: --- Chunk Response
+
+/// Together reports `eos` when the model generated its end of sequence token.
+fn finish_reason(finish_reason: &str) -> String {
+	match finish_reason {
+		"eos" => "stop".to_string(),
+		other => other.to_string(),
+	}
+}
+
+fn usage(usage: &TogetherChatCompletionResponseUsage) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: usage.completion_tokens,
+		prompt_tokens: usage.prompt_tokens,
+		total_tokens: usage.total_tokens,
+		prompt_tokens_details: usage.cached_tokens.map(|cached_tokens| {
+			OpenAIChatCompletionResponseUsagePromptTokensDetails {
+				cached_tokens: Some(cached_tokens),
+			}
+		}),
+	}
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: TogetherChatCompletionObjectResponse = serde_json::from_value(json!({
+		  "id": "8448080b880415ea-SJC",
+		  "object": "chat.completion",
+		  "created": 1705939893,
+		  "model": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+		  "prompt": [],
+		  "choices": [{ "finish_reason": "eos", "index": 0, "message": { "role": "assistant", "content": "Hello!" } }],
+		  "usage": { "prompt_tokens": 31, "completion_tokens": 9, "total_tokens": 40, "cached_tokens": 16 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.choices[0].finish_reason, "stop");
+		assert_eq!(data.response.usage.total_tokens, 40);
+
+		assert_eq!(
+			data.response.usage.prompt_tokens_details,
+			Some(OpenAIChatCompletionResponseUsagePromptTokensDetails { cached_tokens: Some(16) })
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_chunk_response_transform_ok() -> Result<()> {
+		let fx_response: TogetherChatCompletionChunkResponse = serde_json::from_value(json!({
+		  "id": "8448080b880415ea-SJC",
+		  "object": "chat.completion.chunk",
+		  "created": 1705939893,
+		  "model": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+		  "choices": [{ "index": 0, "text": "", "finish_reason": "length", "delta": { "content": "" } }],
+		  "usage": { "prompt_tokens": 31, "completion_tokens": 512, "total_tokens": 543 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.choices[0].finish_reason, Some("length".to_string()));
+		assert_eq!(data.response.usage.map(|usage| usage.completion_tokens), Some(512));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
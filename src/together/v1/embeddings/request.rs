@@ -0,0 +1,49 @@
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbeddingsRequest {
+	/// The name of the embedding model to use.
+	pub model: String,
+
+	/// A string or array of strings providing the text to be embedded.
+	pub input: EmbeddingsRequestInput,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum EmbeddingsRequestInput {
+	String(String),
+	ArrayString(Vec<String>),
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embeddings_request_together_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "togethercomputer/m2-bert-80M-8k-retrieval",
+		  "input": "Our solar system orbits the Milky Way galaxy at about 515,000 mph"
+		})
+		.to_string();
+
+		let data: EmbeddingsRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(
+			data.input,
+			EmbeddingsRequestInput::String(
+				"Our solar system orbits the Milky Way galaxy at about 515,000 mph".to_string()
+			)
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,47 @@
+use crate::openai::v1::embeddings::response::EmbeddingsResponseData;
+
+/// Same as the OpenAI response, but without usage.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbeddingsResponse {
+	/// The object type, which is always "list".
+	pub object: String,
+
+	pub data: Vec<EmbeddingsResponseData>,
+
+	pub model: String,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub request_id: Option<String>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_embeddings_response_together_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "model": "togethercomputer/m2-bert-80M-8k-retrieval",
+		  "object": "list",
+		  "data": [{ "index": 0, "object": "embedding", "embedding": [0.2633975, 0.13856208, 0.14047907] }],
+		  "request_id": "840d1ab5bfcd4d5b-SJC"
+		})
+		.to_string();
+
+		let data: EmbeddingsResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.data.len(), 1);
+		assert_eq!(data.request_id, Some("840d1ab5bfcd4d5b-SJC".to_string()));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,125 @@
+use crate::openai::v1::embeddings::request::{
+	EmbeddingsRequest as OpenAIEmbeddingsRequest,
+	EmbeddingsRequestInput as OpenAIEmbeddingsRequestInput,
+};
+
+use crate::together::v1::embeddings::request::{
+	EmbeddingsRequest as TogetherEmbeddingsRequest,
+	EmbeddingsRequestInput as TogetherEmbeddingsRequestInput,
+};
+
+impl OpenAIEmbeddingsRequest {
+	pub fn to_together_v1(&self) -> Result<Transformation, TransformationError> {
+		let input = match self.input.clone() {
+			OpenAIEmbeddingsRequestInput::String(value) =>
+				TogetherEmbeddingsRequestInput::String(value),
+			OpenAIEmbeddingsRequestInput::ArrayString(values) =>
+				TogetherEmbeddingsRequestInput::ArrayString(values),
+			OpenAIEmbeddingsRequestInput::ArrayInt(_)
+			| OpenAIEmbeddingsRequestInput::ArrayArrayInt(_) =>
+				return Err(TransformationError::TokenInputNotSupported),
+		};
+
+		// Together only returns float values, so any other encoding is reported as lost.
+		let encoding_format = match self.encoding_format.as_deref() {
+			None | Some("float") => None,
+			Some(_) => self.encoding_format.clone(),
+		};
+
+		Ok(Transformation {
+			request: TogetherEmbeddingsRequest { model: self.model.clone(), input },
+			loss: TransformationLoss {
+				encoding_format,
+				dimensions: self.dimensions,
+				user: self.user.clone(),
+			},
+		})
+	}
+}
+
+pub struct TransformationLoss {
+	pub encoding_format: Option<String>,
+	pub dimensions: Option<i64>,
+	pub user: Option<String>,
+}
+
+pub struct Transformation {
+	pub request: TogetherEmbeddingsRequest,
+	pub loss: TransformationLoss,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransformationError {
+	/// Together only embeds text, so token array inputs can't be forwarded.
+	TokenInputNotSupported,
+}
+
+impl std::fmt::Display for TransformationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransformationError::TokenInputNotSupported =>
+				write!(f, "token array inputs are not supported by Together"),
+		}
+	}
+}
+
+impl std::error::Error for TransformationError {}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayString(vec![
+				"hello".to_string(),
+				"world".to_string(),
+			]),
+			model: "BAAI/bge-large-en-v1.5".to_string(),
+			encoding_format: Some("float".to_string()),
+			dimensions: Some(256),
+			user: None,
+		};
+
+		let data = fx_request.to_together_v1()?;
+
+		assert_eq!(
+			data.request.input,
+			TogetherEmbeddingsRequestInput::ArrayString(vec![
+				"hello".to_string(),
+				"world".to_string()
+			])
+		);
+
+		// Check if the parameters Together can't honor were passed to the loss object.
+		assert_eq!(data.loss.dimensions, Some(256));
+		assert_eq!(data.loss.encoding_format, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_token_input_request_transform_fail() -> Result<()> {
+		let fx_request = OpenAIEmbeddingsRequest {
+			input: OpenAIEmbeddingsRequestInput::ArrayArrayInt(vec![vec![1, 2, 3]]),
+			model: "BAAI/bge-large-en-v1.5".to_string(),
+			encoding_format: None,
+			dimensions: None,
+			user: None,
+		};
+
+		assert_eq!(
+			fx_request.to_together_v1().err(),
+			Some(TransformationError::TokenInputNotSupported)
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,2 @@
+pub mod from_openai_v1;
+pub mod to_openai_v1;
@@ -0,0 +1,30 @@
+use crate::openai::v1::embeddings::response::{
+	EmbeddingsResponse as OpenAIEmbeddingsResponse,
+	EmbeddingsResponseUsage as OpenAIEmbeddingsResponseUsage,
+};
+
+use crate::together::v1::embeddings::response::EmbeddingsResponse as TogetherEmbeddingsResponse;
+
+impl TogetherEmbeddingsResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		Transformation {
+			response: OpenAIEmbeddingsResponse {
+				object: "list".to_string(),
+				data: self.data.clone(),
+				model: self.model.clone(),
+				// Together doesn't report the number of tokens embedded.
+				usage: OpenAIEmbeddingsResponseUsage { prompt_tokens: 0, total_tokens: 0 },
+			},
+			loss: TransformationLoss { request_id: self.request_id.clone() },
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub request_id: Option<String>,
+}
+
+pub struct Transformation {
+	pub response: OpenAIEmbeddingsResponse,
+	pub loss: TransformationLoss,
+}
@@ -0,0 +1,76 @@
+/// The request exchanging an IBM Cloud API key for an IAM access token, sent as a form to
+/// `https://iam.cloud.ibm.com/identity/token`. Access tokens expire after an hour, so the exchange
+/// has to be repeated before `expiration`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IamTokenRequest {
+	pub apikey: String,
+}
+
+impl IamTokenRequest {
+	pub const GRANT_TYPE: &'static str = "urn:ibm:params:oauth:grant-type:apikey";
+
+	/// The `application/x-www-form-urlencoded` fields of the request.
+	pub fn form(&self) -> [(&'static str, String); 2] {
+		[("grant_type", Self::GRANT_TYPE.to_string()), ("apikey", self.apikey.clone())]
+	}
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IamTokenResponse {
+	/// The token to send as `Authorization: Bearer <access_token>`.
+	pub access_token: String,
+	pub refresh_token: Option<String>,
+	/// Always `Bearer`.
+	pub token_type: String,
+	/// The lifetime of the token, in seconds.
+	pub expires_in: u64,
+	/// The Unix timestamp (in seconds) at which the token expires.
+	pub expiration: u64,
+}
+
+impl IamTokenResponse {
+	/// Whether the token should be exchanged again, given the current Unix timestamp and a margin
+	/// in seconds to account for clock skew and in flight requests.
+	pub fn needs_refresh(&self, now: u64, margin: u64) -> bool {
+		now + margin >= self.expiration
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_iam_token_response_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "access_token": "eyJhbGciOiJIUz......sgrKIi8hdFs",
+		  "refresh_token": "not_supported",
+		  "token_type": "Bearer",
+		  "expires_in": 3600,
+		  "expiration": 1473188353,
+		  "scope": "ibm openid"
+		})
+		.to_string();
+
+		let data: IamTokenResponse = serde_json::from_str(&fx_response)?;
+
+		assert!(!data.needs_refresh(1473188353 - 3600, 300));
+		assert!(data.needs_refresh(1473188353 - 60, 300));
+		assert_eq!(
+			IamTokenRequest { apikey: "key".to_string() }.form()[1],
+			("apikey", "key".to_string())
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
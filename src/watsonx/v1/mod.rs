@@ -0,0 +1,2 @@
+pub mod iam;
+pub mod text_chat;
@@ -0,0 +1,128 @@
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionTool, ChatCompletionToolChoiceObject,
+};
+
+/// The request of `/ml/v1/text/chat`. The messages and tools have the OpenAI shape, while the
+/// model is scoped to a project or a deployment space.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextChatRequest {
+	/// The model to use for the chat completion, e.g. `ibm/granite-3-8b-instruct`.
+	pub model_id: String,
+
+	/// The messages for this chat session.
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// The project that contains the resource. Either `space_id` or `project_id` has to be given.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub project_id: Option<String>,
+
+	/// The space that contains the resource. Either `space_id` or `project_id` has to be given.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub space_id: Option<String>,
+
+	/// Tool functions that can be called with the response.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	/// Using `none` means the model will not call any tool, `auto` lets it choose and `required`
+	/// forces it to call one or more tools. Only one of `tool_choice_option` and `tool_choice`
+	/// can be given.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice_option: Option<String>,
+
+	/// Forces the model to call the given tool.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoiceObject>,
+
+	/// default: 0
+	/// Positive values penalize new tokens based on their existing frequency in the text so far.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	/// default: false
+	/// Whether to return log probabilities of the output tokens.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<bool>,
+
+	/// The number of most likely tokens to return at each token position.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_logprobs: Option<i64>,
+
+	/// default: 1024
+	/// The maximum number of tokens that can be generated in the chat completion.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	/// default: 1
+	/// How many chat completion choices to generate for each input message.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub n: Option<u64>,
+
+	/// default: 0
+	/// Positive values penalize new tokens based on whether they appear in the text so far.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	/// The chat response format parameters, e.g. `{ "type": "json_object" }`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	/// Random number generator seed to use in sampling mode for experimental repeatability.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	/// Stop sequences are one or more strings which will cause the text generation to stop.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<Vec<String>>,
+
+	/// default: 1
+	/// What sampling temperature to use, between 0 and 2.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	/// default: 1
+	/// An alternative to sampling with temperature, called nucleus sampling.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	/// Time limit in milliseconds. If not completed within this time, generation will stop.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub time_limit: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_text_chat_request_watsonx_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model_id": "ibm/granite-3-8b-instruct",
+		  "project_id": "63dc4cf1-252f-424b-b52d-5cdd9814987f",
+		  "messages": [
+			{ "role": "system", "content": "You are a helpful assistant." },
+			{ "role": "user", "content": [{ "type": "text", "text": "Who won the world series in 2020?" }] }
+		  ],
+		  "tool_choice": { "type": "function", "function": { "name": "get_current_weather" } },
+		  "max_tokens": 300,
+		  "time_limit": 1000
+		})
+		.to_string();
+
+		let data: TextChatRequest = serde_json::from_str(&fx_request)?;
+
+		assert_eq!(data.project_id, Some("63dc4cf1-252f-424b-b52d-5cdd9814987f".to_string()));
+		assert!(data.tool_choice.is_some());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
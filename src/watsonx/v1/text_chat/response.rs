@@ -0,0 +1,112 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponseChoice, ChatCompletionObjectResponseChoice,
+	ChatCompletionResponseUsage,
+};
+
+/// The response of `/ml/v1/text/chat`. The choices and usage have the OpenAI shape.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextChatResponse {
+	pub id: String,
+
+	/// The model used for the chat completion.
+	pub model_id: String,
+
+	/// The version of the model used.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub model_version: Option<String>,
+
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: u64,
+
+	/// The time when the response was created, in ISO 8601 format.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub created_at: Option<String>,
+
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+
+	pub usage: ChatCompletionResponseUsage,
+}
+
+/// One event of the `/ml/v1/text/chat_stream` server-sent events.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextChatStreamResponse {
+	pub id: String,
+
+	pub model_id: String,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub model_version: Option<String>,
+
+	pub created: u64,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub created_at: Option<String>,
+
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_text_chat_response_watsonx_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "chat-c3fde07b6b6a45c9a4e3a1a6b0e2a5c1",
+		  "model_id": "ibm/granite-3-8b-instruct",
+		  "model": "ibm/granite-3-8b-instruct",
+		  "choices": [
+			{
+			  "index": 0,
+			  "message": { "role": "assistant", "content": "The Los Angeles Dodgers won the World Series in 2020." },
+			  "finish_reason": "stop"
+			}
+		  ],
+		  "created": 1728806727,
+		  "model_version": "1.1.0",
+		  "created_at": "2024-10-13T08:05:28.208Z",
+		  "usage": { "completion_tokens": 14, "prompt_tokens": 47, "total_tokens": 61 }
+		})
+		.to_string();
+
+		let data: TextChatResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.model_version, Some("1.1.0".to_string()));
+		assert_eq!(data.usage.total_tokens, 61);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_text_chat_stream_response_watsonx_example_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "chat-c3fde07b6b6a45c9a4e3a1a6b0e2a5c1",
+		  "model_id": "ibm/granite-3-8b-instruct",
+		  "created": 1728806727,
+		  "created_at": "2024-10-13T08:05:28.208Z",
+		  "choices": [{ "index": 0, "finish_reason": null, "delta": { "content": "The" } }],
+		  "usage": { "completion_tokens": 1, "prompt_tokens": 47, "total_tokens": 48 }
+		})
+		.to_string();
+
+		let data: TextChatStreamResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.choices[0].delta.content, Some("The".to_string()));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
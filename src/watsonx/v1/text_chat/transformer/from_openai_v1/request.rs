@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionRequest as OpenAIChatCompletionRequest,
+	ChatCompletionStop as OpenAIChatCompletionStop,
+	ChatCompletionToolChoice as OpenAIChatCompletionToolChoice,
+};
+
+use crate::watsonx::v1::text_chat::request::TextChatRequest as WatsonxTextChatRequest;
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_watsonx_v1(&self, context: TransformationContext) -> Transformation {
+		// watsonx splits the OpenAI tool choice into a mode and a named tool.
+		let (tool_choice_option, tool_choice) = match self.tool_choice.clone() {
+			Some(OpenAIChatCompletionToolChoice::StringChoice(mode)) => (Some(mode), None),
+			Some(OpenAIChatCompletionToolChoice::FunctionChoice(choice)) => (None, Some(choice)),
+			None => (None, None),
+		};
+
+		Transformation {
+			request: WatsonxTextChatRequest {
+				model_id: context.model_id.unwrap_or_else(|| self.model.clone()),
+				messages: self.messages.clone(),
+				project_id: match &context.scope {
+					Scope::Project(id) => Some(id.clone()),
+					Scope::Space(_) => None,
+				},
+				space_id: match &context.scope {
+					Scope::Space(id) => Some(id.clone()),
+					Scope::Project(_) => None,
+				},
+				tools: self.tools.clone(),
+				tool_choice_option,
+				tool_choice,
+				frequency_penalty: self.frequency_penalty,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				max_tokens: self.max_tokens,
+				n: self.n,
+				presence_penalty: self.presence_penalty,
+				response_format: self.response_format.clone(),
+				seed: self.seed,
+				stop: self.stop.clone().map(|stop| match stop {
+					OpenAIChatCompletionStop::StringStop(v) => vec![v],
+					OpenAIChatCompletionStop::ArrayStop(v) => v,
+				}),
+				temperature: self.temperature,
+				top_p: self.top_p,
+				time_limit: None,
+			},
+			loss: TransformationLoss {
+				logit_bias: self.logit_bias.clone(),
+				user: self.user.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub logit_bias: Option<HashMap<String, i32>>,
+	pub user: Option<String>,
+}
+
+pub struct TransformationContext {
+	/// The watsonx model id, when it differs from the requested model.
+	pub model_id: Option<String>,
+	/// Usually set on the connection.
+	pub scope: Scope,
+}
+
+/// The project or deployment space that contains the model, one of which has to be given.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Scope {
+	/// A `project_id`.
+	Project(String),
+	/// A `space_id`.
+	Space(String),
+}
+
+pub struct Transformation {
+	pub request: WatsonxTextChatRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "granite",
+		  "messages": [{ "role": "user", "content": "What is the weather like in Boston today?" }],
+		  "tools": [{ "type": "function", "function": { "name": "get_current_weather" } }],
+		  "tool_choice": "required",
+		  "stop": "\n\n",
+		  "user": "user-1234"
+		}))?;
+
+		let data = fx_request.to_watsonx_v1(TransformationContext {
+			model_id: Some("ibm/granite-3-8b-instruct".to_string()),
+			scope: Scope::Project("63dc4cf1-252f-424b-b52d-5cdd9814987f".to_string()),
+		});
+
+		assert_eq!(data.request.model_id, "ibm/granite-3-8b-instruct");
+		assert_eq!(
+			data.request.project_id,
+			Some("63dc4cf1-252f-424b-b52d-5cdd9814987f".to_string())
+		);
+		assert_eq!(data.request.space_id, None);
+		assert_eq!(data.request.tool_choice_option, Some("required".to_string()));
+		assert_eq!(data.request.tool_choice, None);
+		assert_eq!(data.request.stop, Some(vec!["\n\n".to_string()]));
+
+		// Check if the user was passed to the loss object.
+		assert_eq!(data.loss.user, Some("user-1234".to_string()));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
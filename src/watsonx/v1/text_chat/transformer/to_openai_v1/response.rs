@@ -0,0 +1,136 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+};
+
+use crate::watsonx::v1::text_chat::response::{
+	TextChatResponse as WatsonxTextChatResponse,
+	TextChatStreamResponse as WatsonxTextChatStreamResponse,
+};
+
+// region:    --- Object Response
+impl WatsonxTextChatResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices: self.choices.clone(),
+				created: self.created,
+				model: self.model_id.clone(),
+				system_fingerprint: None,
+				object: "chat.completion".to_string(),
+				usage: self.usage.clone(),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				model_version: self.model_version.clone(),
+				created_at: self.created_at.clone(),
+			},
+		}
+	}
+}
+
+pub struct TransformationLoss {
+	pub model_version: Option<String>,
+	pub created_at: Option<String>,
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Response
+impl WatsonxTextChatStreamResponse {
+	pub fn to_openai_v1(&self) -> StreamTransformation {
+		// watsonx sends the running usage with every event, OpenAI only with the last one.
+		let finished = self.choices.iter().any(|choice| choice.finish_reason.is_some());
+
+		StreamTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices: self.choices.clone(),
+				created: self.created,
+				model: self.model_id.clone(),
+				system_fingerprint: None,
+				object: "chat.completion.chunk".to_string(),
+				usage: if finished { self.usage.clone() } else { None },
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				model_version: self.model_version.clone(),
+				created_at: self.created_at.clone(),
+			},
+		}
+	}
+}
+
+pub struct StreamTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Stream Response
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: WatsonxTextChatResponse = serde_json::from_value(json!({
+		  "id": "chat-c3fde07b6b6a45c9a4e3a1a6b0e2a5c1",
+		  "model_id": "ibm/granite-3-8b-instruct",
+		  "choices": [{ "index": 0, "message": { "role": "assistant", "content": "Hello!" }, "finish_reason": "stop" }],
+		  "created": 1728806727,
+		  "model_version": "1.1.0",
+		  "usage": { "completion_tokens": 2, "prompt_tokens": 47, "total_tokens": 49 }
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(data.response.model, "ibm/granite-3-8b-instruct");
+		assert_eq!(data.response.usage.total_tokens, 49);
+
+		// Check if the model version was passed to the loss object.
+		assert_eq!(data.loss.model_version, Some("1.1.0".to_string()));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stream_response_transform_ok() -> Result<()> {
+		let fx_events: Vec<WatsonxTextChatStreamResponse> = serde_json::from_value(json!([
+		  {
+			"id": "chat-c3fde07b6b6a45c9a4e3a1a6b0e2a5c1",
+			"model_id": "ibm/granite-3-8b-instruct",
+			"created": 1728806727,
+			"choices": [{ "index": 0, "finish_reason": null, "delta": { "content": "Hello" } }],
+			"usage": { "completion_tokens": 1, "prompt_tokens": 47, "total_tokens": 48 }
+		  },
+		  {
+			"id": "chat-c3fde07b6b6a45c9a4e3a1a6b0e2a5c1",
+			"model_id": "ibm/granite-3-8b-instruct",
+			"created": 1728806727,
+			"choices": [{ "index": 0, "finish_reason": "stop", "delta": { "content": "!" } }],
+			"usage": { "completion_tokens": 2, "prompt_tokens": 47, "total_tokens": 49 }
+		  }
+		]))?;
+
+		let data: Vec<OpenAIChatCompletionChunkResponse> =
+			fx_events.iter().map(|event| event.to_openai_v1().response).collect();
+
+		assert_eq!(data[0].usage, None);
+		assert_eq!(data[1].usage.as_ref().map(|usage| usage.total_tokens), Some(49));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::request::{
+	ChatCompletionMessage, ChatCompletionStop, ChatCompletionTool, ChatCompletionToolChoice,
+};
+
+/// xAI accepts the OpenAI request, extended with the Live Search parameters.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionRequest {
+	/// The model to use, e.g. `grok-3`.
+	pub model: String,
+
+	pub messages: Vec<ChatCompletionMessage>,
+
+	/// Lets the model search the web, X, news and RSS feeds before answering.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub search_parameters: Option<SearchParameters>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub n: Option<u64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub frequency_penalty: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub temperature: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logprobs: Option<bool>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_logprobs: Option<i64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_tokens: Option<u64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub presence_penalty: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub top_p: Option<f64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stream: Option<bool>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub stop: Option<ChatCompletionStop>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub user: Option<String>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub seed: Option<i64>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub response_format: Option<serde_json::Value>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub logit_bias: Option<HashMap<String, i32>>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tools: Option<Vec<ChatCompletionTool>>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub tool_choice: Option<ChatCompletionToolChoice>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchParameters {
+	/// default: auto
+	/// `off` disables the search, `on` always searches and `auto` lets the model decide.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub mode: Option<String>,
+
+	/// default: true
+	/// Whether to return the URLs of the sources used in `citations`.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub return_citations: Option<bool>,
+
+	/// Only search data published on or after this date, in ISO 8601 format (`YYYY-MM-DD`).
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub from_date: Option<String>,
+
+	/// Only search data published on or before this date, in ISO 8601 format (`YYYY-MM-DD`).
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub to_date: Option<String>,
+
+	/// default: 20
+	/// The maximum number of sources to consider. Every source used is billed.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub max_search_results: Option<u64>,
+
+	/// default: web and x
+	/// The sources to search.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub sources: Option<Vec<SearchSource>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum SearchSource {
+	#[cfg_attr(feature = "serde", serde(rename = "web", alias = "web"))]
+	WebSource {
+		/// ISO alpha-2 code of the country to search from.
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		country: Option<String>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		allowed_websites: Option<Vec<String>>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		excluded_websites: Option<Vec<String>>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		safe_search: Option<bool>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "x", alias = "x"))]
+	XSource {
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		included_x_handles: Option<Vec<String>>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		excluded_x_handles: Option<Vec<String>>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		post_favorite_count: Option<u64>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		post_view_count: Option<u64>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "news", alias = "news"))]
+	NewsSource {
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		country: Option<String>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		excluded_websites: Option<Vec<String>>,
+		#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+		safe_search: Option<bool>,
+	},
+	#[cfg_attr(feature = "serde", serde(rename = "rss", alias = "rss"))]
+	RssSource {
+		/// The RSS feed to search. Only one link is supported.
+		links: Vec<String>,
+	},
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_request_xai_live_search_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_request = json!({
+		  "model": "grok-3-latest",
+		  "messages": [{ "role": "user", "content": "Provide me a digest of world news in the last 24 hours." }],
+		  "search_parameters": {
+			"mode": "auto",
+			"sources": [{ "type": "web", "country": "CH" }, { "type": "x" }, { "type": "rss", "links": ["https://status.x.ai/feed.xml"] }]
+		  }
+		})
+		.to_string();
+
+		let data: ChatCompletionRequest = serde_json::from_str(&fx_request)?;
+
+		let sources =
+			data.search_parameters.and_then(|parameters| parameters.sources).unwrap_or_default();
+		assert_eq!(sources.len(), 3);
+		assert_eq!(
+			sources[2],
+			SearchSource::RssSource { links: vec!["https://status.x.ai/feed.xml".to_string()] }
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,115 @@
+use crate::openai::v1::chat_completion::response::{
+	ChatCompletionChunkResponseChoice, ChatCompletionObjectResponseChoice,
+	ChatCompletionResponseUsagePromptTokensDetails,
+};
+
+/// The choices have the OpenAI shape, while Live Search adds the citations and the number of
+/// sources used.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionObjectResponse {
+	pub id: String,
+
+	/// The object type, which is always `chat.completion`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub model: String,
+
+	pub choices: Vec<ChatCompletionObjectResponseChoice>,
+
+	pub usage: ChatCompletionResponseUsage,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system_fingerprint: Option<String>,
+
+	/// The URLs of the sources used by Live Search.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub citations: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionChunkResponse {
+	pub id: String,
+
+	/// The object type, which is always `chat.completion.chunk`.
+	pub object: String,
+
+	pub created: u64,
+
+	pub model: String,
+
+	pub choices: Vec<ChatCompletionChunkResponseChoice>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub usage: Option<ChatCompletionResponseUsage>,
+
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub system_fingerprint: Option<String>,
+
+	/// The URLs of the sources used by Live Search, only sent with the last chunk.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub citations: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatCompletionResponseUsage {
+	pub prompt_tokens: u64,
+	pub completion_tokens: u64,
+	pub total_tokens: u64,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub prompt_tokens_details: Option<ChatCompletionResponseUsagePromptTokensDetails>,
+	/// The number of Live Search sources used, which are billed per source.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	pub num_sources_used: Option<u64>,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_response_object_01_decode_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_response = json!({
+		  "id": "a3d1008e-4544-40d4-d075-11527e794e4a",
+		  "object": "chat.completion",
+		  "created": 1752854522,
+		  "model": "grok-3-latest",
+		  "choices": [
+			{
+			  "index": 0,
+			  "message": { "role": "assistant", "content": "Here is a digest of the news.", "refusal": null },
+			  "finish_reason": "stop"
+			}
+		  ],
+		  "usage": {
+			"prompt_tokens": 1431,
+			"completion_tokens": 342,
+			"total_tokens": 1773,
+			"prompt_tokens_details": { "text_tokens": 1431, "audio_tokens": 0, "image_tokens": 0, "cached_tokens": 1024 },
+			"num_sources_used": 5
+		  },
+		  "system_fingerprint": "fp_1234567890",
+		  "citations": ["https://x.com/xai/status/1", "https://www.bbc.com/news"]
+		})
+		.to_string();
+
+		let data: ChatCompletionObjectResponse = serde_json::from_str(&fx_response)?;
+
+		assert_eq!(data.usage.num_sources_used, Some(5));
+		assert_eq!(data.citations.map(|citations| citations.len()), Some(2));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
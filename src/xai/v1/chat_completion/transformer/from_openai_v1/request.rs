@@ -0,0 +1,84 @@
+use crate::openai::v1::chat_completion::request::ChatCompletionRequest as OpenAIChatCompletionRequest;
+
+use crate::xai::v1::chat_completion::request::{
+	ChatCompletionRequest as XAIChatCompletionRequest, SearchParameters as XAISearchParameters,
+};
+
+impl OpenAIChatCompletionRequest {
+	pub fn to_xai_v1(&self, context: TransformationContext) -> Transformation {
+		Transformation {
+			request: XAIChatCompletionRequest {
+				model: self.model.clone(),
+				messages: self.messages.clone(),
+				search_parameters: context.search_parameters,
+				n: self.n,
+				frequency_penalty: self.frequency_penalty,
+				temperature: self.temperature,
+				logprobs: self.logprobs,
+				top_logprobs: self.top_logprobs,
+				max_tokens: self.max_tokens,
+				presence_penalty: self.presence_penalty,
+				top_p: self.top_p,
+				stream: self.stream,
+				stop: self.stop.clone(),
+				user: self.user.clone(),
+				seed: self.seed,
+				response_format: self.response_format.clone(),
+				logit_bias: self.logit_bias.clone(),
+				tools: self.tools.clone(),
+				tool_choice: self.tool_choice.clone(),
+			},
+			loss: TransformationLoss {},
+		}
+	}
+}
+
+pub struct TransformationLoss {}
+
+pub struct TransformationContext {
+	/// The Live Search parameters, usually set on the connection since OpenAI requests can't
+	/// carry them.
+	pub search_parameters: Option<XAISearchParameters>,
+}
+
+pub struct Transformation {
+	pub request: XAIChatCompletionRequest,
+	pub loss: TransformationLoss,
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn test_basic_request_transform_ok() -> Result<()> {
+		let fx_request: OpenAIChatCompletionRequest = serde_json::from_value(json!({
+		  "model": "grok-3-latest",
+		  "messages": [{ "role": "user", "content": "What happened today?" }],
+		  "max_tokens": 1024
+		}))?;
+		let fx_search_parameters = XAISearchParameters {
+			mode: Some("on".to_string()),
+			max_search_results: Some(5),
+			..Default::default()
+		};
+
+		let data = fx_request.to_xai_v1(TransformationContext {
+			search_parameters: Some(fx_search_parameters.clone()),
+		});
+
+		assert_eq!(data.request.messages, fx_request.messages);
+		assert_eq!(data.request.max_tokens, Some(1024));
+		assert_eq!(data.request.search_parameters, Some(fx_search_parameters));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use crate::openai::v1::chat_completion::{
+	citations::{CitationSource, Citations},
+	response::{
+		ChatCompletionChunkResponse as OpenAIChatCompletionChunkResponse,
+		ChatCompletionObjectResponse as OpenAIChatCompletionObjectResponse,
+		ChatCompletionResponseUsage as OpenAIChatCompletionResponseUsage,
+	},
+};
+
+use crate::xai::v1::chat_completion::response::{
+	ChatCompletionChunkResponse as XAIChatCompletionChunkResponse,
+	ChatCompletionObjectResponse as XAIChatCompletionObjectResponse,
+	ChatCompletionResponseUsage as XAIChatCompletionResponseUsage,
+};
+
+// region:    --- Object Response
+impl XAIChatCompletionObjectResponse {
+	pub fn to_openai_v1(&self) -> Transformation {
+		let sources = sources(&self.citations);
+		let mut citations = Vec::<Citations>::new();
+
+		let choices = self
+			.choices
+			.clone()
+			.into_iter()
+			.map(|mut choice| {
+				let choice_citations = Citations::from_content(
+					choice.message.content.as_deref().unwrap_or_default(),
+					&sources,
+				);
+				choice.message.annotations = choice_citations.message_annotations();
+				citations.push(choice_citations);
+				choice
+			})
+			.collect();
+
+		Transformation {
+			response: OpenAIChatCompletionObjectResponse {
+				id: self.id.clone(),
+				choices,
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: self.system_fingerprint.clone(),
+				object: "chat.completion".to_string(),
+				usage: usage(&self.usage),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				unreferenced_sources: unreferenced(&sources, &citations),
+				num_sources_used: self.usage.num_sources_used,
+			},
+		}
+	}
+}
+
+pub struct Transformation {
+	pub response: OpenAIChatCompletionObjectResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Object Response
+
+// region:    --- Stream Response
+impl XAIChatCompletionChunkResponse {
+	/// The citations are only sent with the last chunk, so the content is collected in the
+	/// context and the annotations are sent with the chunk that finishes the choice, like for
+	/// Perplexity.
+	pub fn to_openai_v1(&self, context: &mut StreamTransformationContext) -> StreamTransformation {
+		let sources = sources(&self.citations);
+		if !sources.is_empty() {
+			context.sources = sources;
+		}
+
+		let mut citations = Vec::<Citations>::new();
+		let choices = self
+			.choices
+			.clone()
+			.into_iter()
+			.map(|mut choice| {
+				let content = context.contents.entry(choice.index).or_default();
+				content.push_str(choice.delta.content.as_deref().unwrap_or_default());
+				if choice.finish_reason.is_some() {
+					let choice_citations = Citations::from_content(content, &context.sources);
+					choice.delta.annotations = choice_citations.message_annotations();
+					citations.push(choice_citations);
+				}
+				choice
+			})
+			.collect();
+
+		StreamTransformation {
+			response: OpenAIChatCompletionChunkResponse {
+				id: self.id.clone(),
+				choices,
+				created: self.created,
+				model: self.model.clone(),
+				system_fingerprint: self.system_fingerprint.clone(),
+				object: "chat.completion.chunk".to_string(),
+				usage: self.usage.as_ref().map(usage),
+				service_tier: None,
+			},
+			loss: TransformationLoss {
+				unreferenced_sources: if citations.is_empty() {
+					Vec::new()
+				} else {
+					unreferenced(&context.sources, &citations)
+				},
+				num_sources_used: self.usage.as_ref().and_then(|usage| usage.num_sources_used),
+			},
+		}
+	}
+}
+
+pub struct StreamTransformationContext {
+	contents: HashMap<u64, String>,
+	sources: Vec<CitationSource>,
+}
+
+impl StreamTransformationContext {
+	pub fn new() -> Self {
+		StreamTransformationContext { contents: HashMap::new(), sources: Vec::new() }
+	}
+}
+
+impl Default for StreamTransformationContext {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+pub struct StreamTransformation {
+	pub response: OpenAIChatCompletionChunkResponse,
+	pub loss: TransformationLoss,
+}
+// endregion: --- Stream Response
+
+pub struct TransformationLoss {
+	/// The Live Search sources the content never refers to, so they have no annotation.
+	pub unreferenced_sources: Vec<CitationSource>,
+	pub num_sources_used: Option<u64>,
+}
+
+fn usage(usage: &XAIChatCompletionResponseUsage) -> OpenAIChatCompletionResponseUsage {
+	OpenAIChatCompletionResponseUsage {
+		completion_tokens: usage.completion_tokens,
+		prompt_tokens: usage.prompt_tokens,
+		total_tokens: usage.total_tokens,
+		prompt_tokens_details: usage.prompt_tokens_details.clone(),
+	}
+}
+
+/// xAI only returns the URLs of the sources, which then also serve as titles.
+fn sources(citations: &Option<Vec<String>>) -> Vec<CitationSource> {
+	citations
+		.iter()
+		.flatten()
+		.map(|url| CitationSource { title: url.clone(), url: url.clone() })
+		.collect()
+}
+
+/// The sources that none of the choices refer to.
+fn unreferenced(sources: &[CitationSource], citations: &[Citations]) -> Vec<CitationSource> {
+	sources
+		.iter()
+		.filter(|source| citations.iter().all(|citations| citations.unreferenced.contains(source)))
+		.cloned()
+		.collect()
+}
+
+// region:    --- Tests
+#[cfg(test)]
+mod tests {
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>; // For early tests.
+
+	use serde_json::json;
+
+	use super::*;
+	use crate::openai::v1::chat_completion::response::{
+		ChatCompletionResponseAnnotation as OpenAIChatCompletionResponseAnnotation,
+		ChatCompletionResponseUrlCitation as OpenAIChatCompletionResponseUrlCitation,
+	};
+
+	#[test]
+	fn test_object_response_transform_ok() -> Result<()> {
+		let fx_response: XAIChatCompletionObjectResponse = serde_json::from_value(json!({
+		  "id": "a3d1008e-4544-40d4-d075-11527e794e4a",
+		  "object": "chat.completion",
+		  "created": 1752854522,
+		  "model": "grok-3-latest",
+		  "choices": [{ "index": 0, "finish_reason": "stop", "message": { "role": "assistant", "content": "xAI released Grok 4 [1]." } }],
+		  "usage": { "prompt_tokens": 1431, "completion_tokens": 342, "total_tokens": 1773, "num_sources_used": 2 },
+		  "citations": ["https://x.com/xai/status/1", "https://www.bbc.com/news"]
+		}))?;
+
+		let data = fx_response.to_openai_v1();
+
+		assert_eq!(
+			data.response.choices[0].message.annotations,
+			Some(vec![OpenAIChatCompletionResponseAnnotation::UrlCitation {
+				url_citation: OpenAIChatCompletionResponseUrlCitation {
+					start_index: 20,
+					end_index: 22,
+					url: "https://x.com/xai/status/1".to_string(),
+					title: "https://x.com/xai/status/1".to_string()
+				}
+			}])
+		);
+
+		// Check if the unreferenced source and the number of sources were passed to the loss
+		// object.
+		assert_eq!(
+			data.loss.unreferenced_sources,
+			vec![CitationSource {
+				title: "https://www.bbc.com/news".to_string(),
+				url: "https://www.bbc.com/news".to_string()
+			}]
+		);
+		assert_eq!(data.loss.num_sources_used, Some(2));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_chunk_response_transform_ok() -> Result<()> {
+		let fx_chunks: Vec<XAIChatCompletionChunkResponse> = serde_json::from_value(json!([
+		  {
+			"id": "a3d1008e-4544-40d4-d075-11527e794e4a",
+			"object": "chat.completion.chunk",
+			"created": 1752854522,
+			"model": "grok-3-latest",
+			"choices": [{ "index": 0, "delta": { "role": "assistant", "content": "See the news [1]." } }]
+		  },
+		  {
+			"id": "a3d1008e-4544-40d4-d075-11527e794e4a",
+			"object": "chat.completion.chunk",
+			"created": 1752854522,
+			"model": "grok-3-latest",
+			"choices": [{ "index": 0, "delta": {}, "finish_reason": "stop" }],
+			"usage": { "prompt_tokens": 1431, "completion_tokens": 342, "total_tokens": 1773, "num_sources_used": 5 },
+			"citations": ["https://www.bbc.com/news"]
+		  }
+		]))?;
+
+		let mut context = StreamTransformationContext::new();
+		let data: Vec<StreamTransformation> =
+			fx_chunks.iter().map(|chunk| chunk.to_openai_v1(&mut context)).collect();
+
+		assert_eq!(data[0].response.choices[0].delta.annotations, None);
+		assert_eq!(data[1].response.choices[0].finish_reason, Some("stop".to_string()));
+		assert_eq!(
+			data[1].response.choices[0].delta.annotations,
+			Some(vec![OpenAIChatCompletionResponseAnnotation::UrlCitation {
+				url_citation: OpenAIChatCompletionResponseUrlCitation {
+					start_index: 13,
+					end_index: 15,
+					url: "https://www.bbc.com/news".to_string(),
+					title: "https://www.bbc.com/news".to_string()
+				}
+			}])
+		);
+		assert_eq!(data[1].response.usage.as_ref().map(|usage| usage.total_tokens), Some(1773));
+
+		// Check if the number of sources was passed to the loss object.
+		assert_eq!(data[1].loss.num_sources_used, Some(5));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests